@@ -5,7 +5,12 @@
 //! before any built-in taggers.
 //!
 //! Example: ("linux", "Linux"), ("gee pee tee", "GPT")
+//!
+//! Rules can also be loaded in bulk from NeMo-style `spoken~written` lines
+//! with [`load_from_str`] or [`load_from_file`].
 
+use std::fs;
+use std::path::Path;
 use std::sync::RwLock;
 
 use lazy_static::lazy_static;
@@ -20,8 +25,12 @@ lazy_static! {
 /// The spoken form is stored lowercased for case-insensitive matching.
 /// If the same spoken form already exists, it is replaced.
 pub fn add_rule(spoken: &str, written: &str) {
+    insert(&mut CUSTOM_RULES.write().unwrap(), spoken, written);
+}
+
+/// Add a mapping to a rule list, replacing an existing spoken form.
+pub(crate) fn insert(rules: &mut Vec<(String, String)>, spoken: &str, written: &str) {
     let spoken_lower = spoken.to_lowercase();
-    let mut rules = CUSTOM_RULES.write().unwrap();
     // Replace if exists
     if let Some(entry) = rules.iter_mut().find(|(s, _)| *s == spoken_lower) {
         entry.1 = written.to_string();
@@ -30,12 +39,45 @@ pub fn add_rule(spoken: &str, written: &str) {
     }
 }
 
+/// Add rules from `spoken~written` lines, the NeMo test file format.
+///
+/// Blank lines and lines starting with `#` are skipped, as are malformed
+/// lines without a `~`. Returns the number of rules added.
+pub fn load_from_str(contents: &str) -> usize {
+    let mut count = 0;
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let Some((spoken, written)) = line.split_once('~') else {
+            continue;
+        };
+        if spoken.trim().is_empty() {
+            continue;
+        }
+        add_rule(spoken.trim(), written.trim());
+        count += 1;
+    }
+    count
+}
+
+/// Add rules from a `spoken~written` file. See [`load_from_str`].
+pub fn load_from_file(path: &Path) -> std::io::Result<usize> {
+    let contents = fs::read_to_string(path)?;
+    Ok(load_from_str(&contents))
+}
+
 /// Remove a custom rule by its spoken form.
 ///
 /// Returns true if the rule was found and removed.
 pub fn remove_rule(spoken: &str) -> bool {
+    remove(&mut CUSTOM_RULES.write().unwrap(), spoken)
+}
+
+/// Remove a spoken form from a rule list.
+pub(crate) fn remove(rules: &mut Vec<(String, String)>, spoken: &str) -> bool {
     let spoken_lower = spoken.to_lowercase();
-    let mut rules = CUSTOM_RULES.write().unwrap();
     let len_before = rules.len();
     rules.retain(|(s, _)| *s != spoken_lower);
     rules.len() < len_before
@@ -51,11 +93,15 @@ pub fn clear_rules() {
 ///
 /// Returns `Some(written_form)` if a rule matches, `None` otherwise.
 pub fn parse(input: &str) -> Option<String> {
+    find(&CUSTOM_RULES.read().unwrap(), input)
+}
+
+/// Match input against a rule list, as [`parse`] does for the global rules.
+pub(crate) fn find(rules: &[(String, String)], input: &str) -> Option<String> {
     let input_lower = input.to_lowercase();
     let input_trimmed = input_lower.trim();
 
-    let rules = CUSTOM_RULES.read().unwrap();
-    for (spoken, written) in rules.iter() {
+    for (spoken, written) in rules {
         if input_trimmed == spoken {
             return Some(written.clone());
         }
@@ -64,6 +110,11 @@ pub fn parse(input: &str) -> Option<String> {
     None
 }
 
+/// Run `f` with the global rules held for reading.
+pub(crate) fn with_global<R>(f: impl FnOnce(&[(String, String)]) -> R) -> R {
+    f(&CUSTOM_RULES.read().unwrap())
+}
+
 /// Get the number of custom rules currently registered.
 pub fn rule_count() -> usize {
     let rules = CUSTOM_RULES.read().unwrap();
@@ -104,5 +155,21 @@ mod tests {
         clear_rules();
         assert_eq!(rule_count(), 0);
         assert_eq!(parse("alpha"), None);
+
+        // Load from a string: comments, blank and malformed lines skipped
+        let contents = "# jargon\n\nkay eight s~k8s\nno tilde here\ngee pee tee ~ GPT\n";
+        assert_eq!(load_from_str(contents), 2);
+        assert_eq!(parse("kay eight s"), Some("k8s".to_string()));
+        assert_eq!(parse("gee pee tee"), Some("GPT".to_string()));
+        clear_rules();
+
+        // Load from a file
+        let path = std::env::temp_dir().join("nemo_custom_rules_test.txt");
+        fs::write(&path, "linux~Linux\nalpha~A\n").unwrap();
+        assert_eq!(load_from_file(&path).unwrap(), 2);
+        assert_eq!(parse("linux"), Some("Linux".to_string()));
+        fs::remove_file(&path).unwrap();
+        assert!(load_from_file(&path).is_err());
+        clear_rules();
     }
 }
@@ -17,95 +17,212 @@
 //! ```
 
 pub mod custom_rules;
+pub mod normalizer;
+pub mod options;
 pub mod taggers;
 
+pub use normalizer::Normalizer;
+pub use options::{NormalizeOptions, NumberPrefixStyle, TemperatureScale};
+
 #[cfg(feature = "ffi")]
 pub mod ffi;
 
-use taggers::{
-    cardinal, date, decimal, electronic, measure, money, ordinal, punctuation, telephone, time,
-    whitelist, word,
-};
+#[cfg(feature = "date")]
+use taggers::date;
+#[cfg(feature = "electronic")]
+use taggers::electronic;
+#[cfg(feature = "fraction")]
+use taggers::fraction;
+#[cfg(feature = "measure")]
+use taggers::measure;
+#[cfg(feature = "money")]
+use taggers::money;
+#[cfg(feature = "range")]
+use taggers::range;
+#[cfg(feature = "reference")]
+use taggers::reference;
+#[cfg(feature = "telephone")]
+use taggers::telephone;
+#[cfg(feature = "time")]
+use taggers::time;
+use taggers::{cardinal, decimal, ordinal, punctuation, whitelist, word};
 
 /// Normalize spoken-form text to written form.
 ///
 /// Tries taggers in order of specificity (most specific first).
 /// Returns original text if no tagger matches.
 pub fn normalize(input: &str) -> String {
+    normalize_with_options(input, &NormalizeOptions::default())
+}
+
+/// Normalize spoken-form text to written form with custom output options.
+///
+/// ```
+/// use nemo_text_processing::{normalize_with_options, NormalizeOptions};
+///
+/// let options = NormalizeOptions {
+///     ellipsis_char: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(normalize_with_options("ellipsis", &options), "…");
+/// ```
+pub fn normalize_with_options(input: &str, options: &NormalizeOptions) -> String {
+    custom_rules::with_global(|rules| normalize_with_rules(input, options, rules))
+}
+
+/// Normalize a whole input against the given custom rules.
+pub(crate) fn normalize_with_rules(
+    input: &str,
+    options: &NormalizeOptions,
+    rules: &[(String, String)],
+) -> String {
     let input = input.trim();
+    if let Some(result) = tag(input, options, rules) {
+        return result;
+    }
+
+    // Fall back to the spans inside an otherwise unmatched input
+    if options.best_effort {
+        return sentence_with_options(input, DEFAULT_MAX_SPAN_TOKENS, options, rules);
+    }
+
+    input.to_string()
+}
+
+/// Check whether `normalize` would change the input, without building the
+/// unchanged result.
+///
+/// ```
+/// use nemo_text_processing::would_normalize;
+///
+/// assert!(would_normalize("twenty one"));
+/// assert!(!would_normalize("hello"));
+/// ```
+pub fn would_normalize(input: &str) -> bool {
+    let trimmed = input.trim();
+    if trimmed.len() != input.len() {
+        return true;
+    }
+    custom_rules::with_global(|rules| tag(input, &NormalizeOptions::default(), rules))
+        .is_some_and(|result| result != input)
+}
 
+/// Run the taggers in order of specificity, returning the first match.
+fn tag(input: &str, options: &NormalizeOptions, rules: &[(String, String)]) -> Option<String> {
     // Apply custom user rules first (highest priority)
-    if let Some(result) = custom_rules::parse(input) {
-        return result;
+    if let Some(result) = custom_rules::find(rules, input) {
+        return Some(result);
     }
 
     // Apply whitelist replacements (abbreviations, special terms)
-    if let Some(result) = whitelist::parse(input) {
-        return result;
+    if let Some(result) = whitelist::parse_with_options(input, options) {
+        return Some(result);
     }
 
     // Try punctuation ("period" → ".", "comma" → ",")
-    if let Some(result) = punctuation::parse(input) {
-        return result;
+    if let Some(result) = punctuation::parse_with_options(input, options) {
+        return Some(result);
     }
 
     // Try word patterns (spelled letters + numbers, numbers with punctuation)
-    if let Some(result) = word::parse(input) {
-        return result;
+    if let Some(result) = word::parse_with_options(input, options) {
+        return Some(result);
+    }
+
+    // Try cue-word references ("check number one zero zero five" → "check #1005")
+    #[cfg(feature = "reference")]
+    if let Some(result) = reference::parse_with_options(input, options) {
+        return Some(result);
+    }
+
+    // A bare military reading ("fourteen hundred") counts only as a whole
+    // input; inside a sentence it is usually a number
+    #[cfg(feature = "time")]
+    if options.military_time {
+        if let Some(result) = time::parse_military(input) {
+            return Some(result);
+        }
+    }
+
+    // Service short codes, where "nine eleven" means 911 rather than 09:11
+    #[cfg(feature = "telephone")]
+    if let Some(result) = telephone::parse_short_code(input) {
+        return Some(result);
     }
 
     // Try time expressions (before telephone to avoid "two thirty" → alphanumeric)
-    if let Some(result) = time::parse(input) {
-        return result;
+    #[cfg(feature = "time")]
+    if let Some(result) = time::parse_with_options(input, options) {
+        return Some(result);
+    }
+    #[cfg(feature = "time")]
+    if time::is_signed_time(input) {
+        return Some(input.to_string());
+    }
+
+    // Try ranges after time, which owns "ten to five"
+    #[cfg(feature = "range")]
+    if let Some(result) = range::parse_with_options(input, options) {
+        return Some(result);
     }
 
     // Try date expressions (before telephone to avoid "nineteen ninety four" → alphanumeric)
+    #[cfg(feature = "date")]
     if let Some(result) = date::parse(input) {
-        return result;
+        return Some(result);
     }
 
     // Try money (contains number + currency) - before telephone
-    if let Some(result) = money::parse(input) {
-        return result;
+    #[cfg(feature = "money")]
+    if let Some(result) = money::parse_with_options(input, options) {
+        return Some(result);
     }
 
     // Try measurements (contains number + unit) - before telephone
-    if let Some(result) = measure::parse(input) {
-        return result;
+    #[cfg(feature = "measure")]
+    if let Some(result) = measure::parse_with_options(input, options) {
+        return Some(result);
     }
 
     // Try decimal numbers (before telephone to catch "sixty point two")
     if let Some(result) = decimal::parse(input) {
-        return result;
+        return Some(result);
+    }
+
+    // Try fractions (before telephone to avoid "one two slash three" → digits)
+    #[cfg(feature = "fraction")]
+    if let Some(result) = fraction::parse_with_options(input, options) {
+        return Some(result);
     }
 
     // Try telephone/IP numbers (before electronic to catch IP addresses)
+    #[cfg(feature = "telephone")]
     if let Some(result) = telephone::parse(input) {
-        return result;
+        return Some(result);
     }
 
     // Try electronic addresses (emails, URLs)
+    #[cfg(feature = "electronic")]
     if let Some(result) = electronic::parse(input) {
-        return result;
+        return Some(result);
     }
 
     // Try decimal numbers
     if let Some(result) = decimal::parse(input) {
-        return result;
+        return Some(result);
     }
 
     // Try ordinal numbers
-    if let Some(result) = ordinal::parse(input) {
-        return result;
+    if let Some(result) = ordinal::parse_with_options(input, options) {
+        return Some(result);
     }
 
     // Try cardinal number
-    if let Some(num) = cardinal::parse(input) {
-        return num;
+    if let Some(num) = cardinal::parse_with_options(input, options) {
+        return Some(num);
     }
 
-    // No match - return original
-    input.to_string()
+    None
 }
 
 /// Normalize with language selection (future use).
@@ -117,53 +234,126 @@ pub fn normalize_with_lang(input: &str, _lang: &str) -> String {
 /// Default maximum token span to consider when scanning a sentence.
 const DEFAULT_MAX_SPAN_TOKENS: usize = 16;
 
+/// `parse_span` score for time spans.
+const TIME_SCORE: u8 = 85;
+
+/// `parse_span` score for reference spans.
+const REFERENCE_SCORE: u8 = 92;
+
+/// `parse_span` score for ordinal spans.
+const ORDINAL_SCORE: u8 = 75;
+
+/// `parse_span` score for a malformed span claimed so that it is kept as
+/// spoken, rather than left for shorter spans to misread piecewise.
+const KEEP_SCORE: u8 = 0;
+
 /// Try to parse a span of text using sentence-safe taggers.
 ///
 /// Returns `(replacement, priority_score)` if a tagger matches.
 /// Taggers are ordered by precision: high-confidence patterns first,
 /// broad patterns (cardinal) last and limited to short spans.
 ///
-/// Excluded in sentence mode: `word` and `telephone` (over-fire on natural language).
-fn parse_span(span: &str) -> Option<(String, u8)> {
+/// When several taggers accept the same span, the first one in this fixed
+/// order wins, so the result for a span never depends on anything but the
+/// span itself.
+///
+/// A span returned unchanged with `KEEP_SCORE` is malformed and stays as
+/// spoken.
+///
+/// Excluded in sentence mode: `word` and `telephone` (over-fire on natural language),
+/// except for the opt-in spelled letters and "plus" phone numbers.
+fn parse_span(
+    span: &str,
+    options: &NormalizeOptions,
+    rules: &[(String, String)],
+) -> Option<(String, u8)> {
     let token_count = span.split_whitespace().count();
     if token_count == 0 {
         return None;
     }
 
-    if let Some(result) = custom_rules::parse(span) {
+    if let Some(result) = custom_rules::find(rules, span) {
         return Some((result, 110));
     }
-    if let Some(result) = whitelist::parse(span) {
+    if let Some(result) = whitelist::parse_with_options(span, options) {
         return Some((result, 100));
     }
-    if let Some(result) = punctuation::parse(span) {
+    if let Some(result) = punctuation::parse_with_options(span, options) {
         return Some((result, 98));
     }
-    if let Some(result) = money::parse(span) {
+    // A leading "plus" is a strong cue for an international number
+    #[cfg(feature = "telephone")]
+    if options.telephone_in_sentence {
+        if let Some(result) = telephone::parse_international(span) {
+            return Some((result, 96));
+        }
+    }
+    #[cfg(feature = "money")]
+    if money::is_point_after_currency(span) {
+        return Some((span.to_string(), KEEP_SCORE));
+    }
+    #[cfg(feature = "money")]
+    if let Some(result) = money::parse_with_options(span, options) {
         return Some((result, 95));
     }
-    if let Some(result) = measure::parse(span) {
+    #[cfg(feature = "reference")]
+    if let Some(result) = reference::parse_with_options(span, options) {
+        return Some((result, REFERENCE_SCORE));
+    }
+    // Military "hours" readings would otherwise be taken as a duration
+    #[cfg(feature = "time")]
+    if options.military_time {
+        if let Some(result) = time::parse_military_hours(span) {
+            return Some((result, 91));
+        }
+    }
+    #[cfg(feature = "measure")]
+    if let Some(result) = measure::parse_with_options(span, options) {
         return Some((result, 90));
     }
+    // Spelled letters are opt-in; the rest of `word` stays out of sentences
+    if options.spell_single_letters {
+        if let Some(result) = word::parse_spelled_letters(span) {
+            return Some((result, 89));
+        }
+    }
+    #[cfg(feature = "date")]
     if let Some(result) = date::parse(span) {
         return Some((result, 88));
     }
-    if let Some(result) = time::parse(span) {
-        return Some((result, 85));
+    #[cfg(feature = "time")]
+    if let Some(result) = time::parse_with_options(span, options) {
+        return Some((result, TIME_SCORE));
     }
+    #[cfg(feature = "time")]
+    if time::is_signed_time(span) {
+        return Some((span.to_string(), KEEP_SCORE));
+    }
+    #[cfg(feature = "range")]
+    if let Some(result) = range::parse_with_options(span, options) {
+        return Some((result, 84));
+    }
+    #[cfg(feature = "electronic")]
     if let Some(result) = electronic::parse(span) {
         return Some((result, 82));
     }
+    if decimal::is_decimal_before_hundred(span) {
+        return Some((span.to_string(), KEEP_SCORE));
+    }
     if let Some(result) = decimal::parse(span) {
         return Some((result, 80));
     }
-    if let Some(result) = ordinal::parse(span) {
-        return Some((result, 75));
+    #[cfg(feature = "fraction")]
+    if let Some(result) = fraction::parse_with_options(span, options) {
+        return Some((result, 78));
+    }
+    if let Some(result) = ordinal::parse_with_options(span, options) {
+        return Some((result, ORDINAL_SCORE));
     }
 
     // Cardinal only for short spans to avoid over-matching on natural language.
     if token_count <= 4 {
-        if let Some(result) = cardinal::parse(span) {
+        if let Some(result) = cardinal::parse_with_options(span, options) {
             return Some((result, 70));
         }
     }
@@ -194,6 +384,9 @@ pub fn normalize_sentence(input: &str) -> String {
 /// Smaller values are faster but may miss multi-word expressions.
 /// Larger values catch more patterns but do more work per token.
 ///
+/// At each position the longest matching span wins. When several taggers
+/// accept that span, the first in `parse_span`'s fixed order wins.
+///
 /// ```
 /// use nemo_text_processing::normalize_sentence_with_max_span;
 ///
@@ -201,9 +394,181 @@ pub fn normalize_sentence(input: &str) -> String {
 /// assert_eq!(normalize_sentence_with_max_span("I have twenty one apples", 4), "I have 21 apples");
 /// ```
 pub fn normalize_sentence_with_max_span(input: &str, max_span_tokens: usize) -> String {
+    custom_rules::with_global(|rules| {
+        sentence_with_options(input, max_span_tokens, &NormalizeOptions::default(), rules)
+    })
+}
+
+/// Normalize a full sentence with custom output options.
+///
+/// Uses the default max span of 16 tokens.
+///
+/// ```
+/// use nemo_text_processing::{normalize_sentence_with_options, NormalizeOptions};
+///
+/// let options = NormalizeOptions {
+///     ellipsis_char: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     normalize_sentence_with_options("wait ellipsis what", &options),
+///     "wait … what"
+/// );
+/// ```
+pub fn normalize_sentence_with_options(input: &str, options: &NormalizeOptions) -> String {
+    custom_rules::with_global(|rules| {
+        sentence_with_options(input, DEFAULT_MAX_SPAN_TOKENS, options, rules)
+    })
+}
+
+/// Normalize a full sentence, showing the original form next to each
+/// replacement.
+///
+/// `template` is applied to every changed span, with `{original}` and
+/// `{normalized}` substituted; unchanged tokens are copied as-is.
+///
+/// ```
+/// use nemo_text_processing::normalize_sentence_with_original;
+///
+/// assert_eq!(
+///     normalize_sentence_with_original("I have twenty one apples", "{original} [{normalized}]"),
+///     "I have twenty one [21] apples"
+/// );
+/// ```
+pub fn normalize_sentence_with_original(input: &str, template: &str) -> String {
+    let segments = custom_rules::with_global(|rules| {
+        sentence_segments(
+            input,
+            DEFAULT_MAX_SPAN_TOKENS,
+            &NormalizeOptions::default(),
+            rules,
+        )
+    });
+    segments
+        .into_iter()
+        .map(|segment| match segment.normalized {
+            Some(normalized) => template
+                .split("{original}")
+                .map(|part| part.replace("{normalized}", &normalized))
+                .collect::<Vec<_>>()
+                .join(&segment.original),
+            None => segment.original,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A span of the input rewritten by [`normalize_sentence_spans`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replacement {
+    /// Byte offset where the span starts in the input.
+    pub start: usize,
+    /// Byte offset just past the span's end.
+    pub end: usize,
+    /// The span as it appears in the input (`&input[start..end]`).
+    pub original: String,
+    /// The written form that replaces it.
+    pub normalized: String,
+    /// The tagger that produced the replacement ("cardinal", "money", ...).
+    pub tagger: &'static str,
+}
+
+/// Normalize a full sentence, returning each rewritten span with its byte
+/// offsets instead of the joined output. Unchanged tokens are left out.
+///
+/// Spans cover whole tokens, so punctuation glued to a number is included
+/// in both `original` and `normalized` ("one," → "1,").
+///
+/// ```
+/// use nemo_text_processing::normalize_sentence_spans;
+///
+/// let spans = normalize_sentence_spans("I have twenty one apples");
+/// assert_eq!(spans.len(), 1);
+/// assert_eq!((spans[0].start, spans[0].end), (7, 17));
+/// assert_eq!(spans[0].original, "twenty one");
+/// assert_eq!(spans[0].normalized, "21");
+/// assert_eq!(spans[0].tagger, "cardinal");
+/// ```
+pub fn normalize_sentence_spans(input: &str) -> Vec<Replacement> {
+    let segments = custom_rules::with_global(|rules| {
+        sentence_segments(
+            input,
+            DEFAULT_MAX_SPAN_TOKENS,
+            &NormalizeOptions::default(),
+            rules,
+        )
+    });
+    segments
+        .into_iter()
+        .filter_map(|segment| {
+            Some(Replacement {
+                start: segment.start,
+                end: segment.end,
+                original: input[segment.start..segment.end].to_string(),
+                normalized: segment.normalized?,
+                tagger: tagger_name(segment.score),
+            })
+        })
+        .collect()
+}
+
+/// Name of the tagger behind a `parse_span` score.
+fn tagger_name(score: u8) -> &'static str {
+    match score {
+        110 => "custom",
+        100 => "whitelist",
+        98 => "punctuation",
+        96 => "telephone",
+        95 => "money",
+        REFERENCE_SCORE => "reference",
+        91 | TIME_SCORE => "time",
+        90 => "measure",
+        89 => "word",
+        88 => "date",
+        84 => "range",
+        82 => "electronic",
+        80 => "decimal",
+        78 => "fraction",
+        ORDINAL_SCORE => "ordinal",
+        70 => "cardinal",
+        _ => "unknown",
+    }
+}
+
+pub(crate) fn sentence_with_options(
+    input: &str,
+    max_span_tokens: usize,
+    options: &NormalizeOptions,
+    rules: &[(String, String)],
+) -> String {
+    sentence_segments(input, max_span_tokens, options, rules)
+        .into_iter()
+        .map(|segment| segment.normalized.unwrap_or(segment.original))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A run of sentence tokens and its replacement, if a tagger matched it.
+struct Segment {
+    original: String,
+    normalized: Option<String>,
+    /// Byte range of the run in the input.
+    start: usize,
+    end: usize,
+    /// `parse_span` score of the tagger that produced `normalized`.
+    score: u8,
+}
+
+/// Split a sentence into replaced spans and passthrough tokens.
+fn sentence_segments(
+    input: &str,
+    max_span_tokens: usize,
+    options: &NormalizeOptions,
+    rules: &[(String, String)],
+) -> Vec<Segment> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
-        return trimmed.to_string();
+        return Vec::new();
     }
 
     let max_span = if max_span_tokens == 0 {
@@ -212,7 +577,37 @@ pub fn normalize_sentence_with_max_span(input: &str, max_span_tokens: usize) ->
         max_span_tokens
     };
     let tokens: Vec<&str> = trimmed.split_whitespace().collect();
-    let mut out: Vec<String> = Vec::with_capacity(tokens.len());
+    // Tokens are slices of `input`, so their byte offsets can be recovered
+    let offset = |token: &str| token.as_ptr() as usize - input.as_ptr() as usize;
+    let segment = |from: usize, to: usize, normalized: Option<(String, u8)>| {
+        let (normalized, score) = match normalized {
+            Some((text, score)) => (Some(text), score),
+            None => (None, 0),
+        };
+        Segment {
+            original: tokens[from..to].join(" "),
+            normalized,
+            start: offset(tokens[from]),
+            end: offset(tokens[to - 1]) + tokens[to - 1].len(),
+            score,
+        }
+    };
+
+    if options
+        .max_input_tokens
+        .is_some_and(|max| tokens.len() > max)
+    {
+        let start = offset(trimmed);
+        return vec![Segment {
+            original: trimmed.to_string(),
+            normalized: None,
+            start,
+            end: start + trimmed.len(),
+            score: 0,
+        }];
+    }
+
+    let mut out: Vec<Segment> = Vec::with_capacity(tokens.len());
     let mut i = 0usize;
 
     while i < tokens.len() {
@@ -222,45 +617,187 @@ pub fn normalize_sentence_with_max_span(input: &str, max_span_tokens: usize) ->
         // Longest-span-first search keeps replacements stable and non-overlapping.
         for end in (i + 1..=max_end).rev() {
             let span = tokens[i..end].join(" ");
-            let Some((candidate, score)) = parse_span(&span) else {
+
+            // Reject no-op results (tagger returned same text).
+            let parse_changed = |text: &str| {
+                parse_span(text, options, rules).filter(|(candidate, score)| {
+                    let candidate = candidate.trim();
+                    *score == KEEP_SCORE || (!candidate.is_empty() && candidate != text)
+                })
+            };
+
+            // Punctuation glued to the span's edges ("one," or "\"two") is
+            // set aside and re-attached to the replacement
+            let Some((candidate, score)) = parse_changed(&span).or_else(|| {
+                let (lead, core, trail) = split_edge_punctuation(&tokens[i..end])?;
+                let (candidate, score) = parse_changed(&core)?;
+                Some((format!("{}{}{}", lead, candidate.trim(), trail), score))
+            }) else {
                 continue;
             };
 
-            // Reject no-op results (tagger returned same text).
-            let candidate_trimmed = candidate.trim();
-            if candidate_trimmed.is_empty() || candidate_trimmed == span {
+            // A number before a currency or unit word is a quantity, not a
+            // clock time or an identifier ("two thirty euro tickets", "each
+            // unit five dollars"); leave it to a shorter or later span
+            if matches!(score, TIME_SCORE | REFERENCE_SCORE)
+                && tokens.get(end).is_some_and(|t| is_quantity_cue(t))
+            {
                 continue;
             }
 
-            let candidate_len = end - i;
-            match &best {
-                None => {
-                    best = Some((end, candidate, score));
-                }
-                Some((best_end, _, best_score)) => {
-                    let best_len = *best_end - i;
-                    if candidate_len > best_len
-                        || (candidate_len == best_len && score > *best_score)
-                    {
-                        best = Some((end, candidate, score));
-                    }
-                }
+            // An identifier followed by another number word is cut short:
+            // "a room two hundred dollars" is not "room 2" then "$100"
+            if score == REFERENCE_SCORE
+                && tokens
+                    .get(end)
+                    .is_some_and(|t| cardinal::words_to_number(&t.to_lowercase()).is_some())
+            {
+                continue;
+            }
+
+            if score == ORDINAL_SCORE
+                && options.ordinal_requires_context
+                && !has_ordinal_context(i.checked_sub(1).map(|j| tokens[j]), tokens.get(end))
+            {
+                continue;
             }
+
+            if options.period_word_sentence_final_only
+                && span.eq_ignore_ascii_case("period")
+                && !is_sentence_final(&tokens[end..], options)
+            {
+                continue;
+            }
+
+            // Spans are tried longest first, so the first match is the
+            // longest. Taggers that accept the same span were already
+            // settled by `parse_span`'s order.
+            best = Some((end, candidate, score));
+            break;
         }
 
-        if let Some((end, replacement, _)) = best {
-            out.push(replacement);
+        if let Some((end, replacement, score)) = best {
+            let normalized = (score != KEEP_SCORE).then_some((replacement, score));
+            out.push(segment(i, end, normalized));
             i = end;
         } else {
-            out.push(tokens[i].to_string());
+            out.push(segment(i, i + 1, None));
             i += 1;
         }
     }
 
-    out.join(" ")
+    out
+}
+
+/// Split ASCII punctuation off the edges of a span: `["\"twenty", "one,"]`
+/// → `("\"", "twenty one", ",")`. Returns `None` when there is nothing to
+/// strip, or when punctuation sits between the span's words ("one, two"),
+/// which would join two separate expressions.
+fn split_edge_punctuation<'a>(tokens: &[&'a str]) -> Option<(&'a str, String, &'a str)> {
+    let is_punct = |c: char| c.is_ascii_punctuation();
+    let first = tokens.first()?;
+    let last = tokens.last()?;
+    if tokens.iter().any(|t| t.chars().all(is_punct)) {
+        return None;
+    }
+
+    let lead = &first[..first.len() - first.trim_start_matches(is_punct).len()];
+    let trail = &last[last.trim_end_matches(is_punct).len()..];
+    if lead.is_empty() && trail.is_empty() {
+        return None;
+    }
+
+    let mut words: Vec<&str> = tokens.to_vec();
+    let n = words.len();
+    words[0] = &words[0][lead.len()..];
+    words[n - 1] = &words[n - 1][..words[n - 1].len() - trail.len()];
+    let inner_punct = words
+        .iter()
+        .enumerate()
+        .any(|(k, w)| (k > 0 && w.starts_with(is_punct)) || (k + 1 < n && w.ends_with(is_punct)));
+    if inner_punct {
+        return None;
+    }
+    Some((lead, words.join(" "), trail))
+}
+
+/// Currency words that make a preceding number a quantity. Singular forms
+/// are included since they are used attributively ("a thirty dollar fine").
+const CURRENCY_CUES: [&str; 16] = [
+    "dollar", "dollars", "cent", "cents", "euro", "euros", "pound", "pounds", "pence", "penny",
+    "yen", "won", "yuan", "rupee", "rupees", "percent",
+];
+
+/// Time units that make a preceding number a duration ("two days later").
+const DURATION_CUES: [&str; 14] = [
+    "second", "seconds", "minute", "minutes", "hour", "hours", "day", "days", "week", "weeks",
+    "month", "months", "year", "years",
+];
+
+/// Check whether a token is a currency, duration or measurement unit word.
+fn is_quantity_cue(token: &str) -> bool {
+    let token = token.to_lowercase();
+    if CURRENCY_CUES.contains(&token.as_str()) || DURATION_CUES.contains(&token.as_str()) {
+        return true;
+    }
+    #[cfg(feature = "measure")]
+    if measure::parse(&format!("two {}", token)).is_some() {
+        return true;
+    }
+    false
+}
+
+/// Words that do not start a noun phrase, so an ordinal before them is
+/// probably an adverb ("first we eat") rather than an adjective.
+const NON_NOUN_WORDS: [&str; 38] = [
+    "i", "you", "he", "she", "it", "we", "they", "me", "him", "her", "us", "them", "let's", "lets",
+    "is", "are", "was", "were", "be", "been", "and", "or", "but", "then", "so", "of", "to", "in",
+    "on", "at", "for", "with", "by", "from", "as", "if", "that", "this",
+];
+
+/// Check whether an ordinal span reads as a number for
+/// `ordinal_requires_context`: it follows "the"/"a"/"an" or a number, or
+/// comes before a word that could start a noun phrase.
+///
+/// This is a word-list heuristic, not a parser: "second thoughts" still
+/// converts, and "she came in third" stays as words.
+fn has_ordinal_context(before: Option<&str>, after: Option<&&str>) -> bool {
+    if let Some(before) = before {
+        let before = before.to_lowercase();
+        if matches!(before.as_str(), "the" | "a" | "an")
+            || before.chars().all(|c| c.is_ascii_digit())
+            || cardinal::words_to_number(&before).is_some()
+        {
+            return true;
+        }
+    }
+    after.is_some_and(|after| {
+        let after = after.to_lowercase();
+        after.chars().all(|c| c.is_alphabetic() || c == '\'')
+            && !NON_NOUN_WORDS.contains(&after.as_str())
+    })
+}
+
+/// Marks that may follow a sentence-final period. Symbols such as "plus"
+/// or "percent" are ordinary words after a noun ("the period plus interest").
+const SENTENCE_FOLLOWERS: [&str; 13] = [
+    ".", ",", "?", "!", ":", ";", ")", "]", "}", "\"", "'", "...", "…",
+];
+
+/// Check whether a token is sentence-final: nothing follows it, or the
+/// next tokens are themselves a spoken punctuation mark ("period comma").
+fn is_sentence_final(rest: &[&str], options: &NormalizeOptions) -> bool {
+    if rest.is_empty() {
+        return true;
+    }
+    // Multi-word punctuation ("question mark") spans at most two tokens
+    (1..=rest.len().min(2)).any(|n| {
+        punctuation::parse_with_options(&rest[..n].join(" "), options)
+            .is_some_and(|mark| SENTENCE_FOLLOWERS.contains(&mark.as_str()))
+    })
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "full"))]
 mod tests {
     use super::*;
 
@@ -279,6 +816,7 @@ mod tests {
     #[test]
     fn test_passthrough() {
         assert_eq!(normalize("hello world"), "hello world");
+        assert_eq!(normalize("million million"), "million million");
     }
 
     #[test]
@@ -339,4 +877,620 @@ mod tests {
         assert_eq!(normalize_sentence("yes comma I agree"), "yes , I agree");
         assert_eq!(normalize_sentence("really question mark"), "really ?");
     }
+
+    #[test]
+    fn test_span_tie_break() {
+        let options = NormalizeOptions::default();
+        // Time and cardinal both accept "two thirty"; time comes first in
+        // `parse_span`, so it wins the span in sentence mode too
+        assert!(cardinal::parse("two thirty").is_some());
+        assert_eq!(
+            parse_span("two thirty", &options, &[]),
+            Some(("02:30".to_string(), 85))
+        );
+        assert_eq!(normalize_sentence("meet at two thirty"), "meet at 02:30");
+    }
+
+    #[test]
+    fn test_sentence_with_original() {
+        assert_eq!(
+            normalize_sentence_with_original(
+                "I have twenty one apples",
+                "{original} [{normalized}]"
+            ),
+            "I have twenty one [21] apples"
+        );
+        assert_eq!(
+            normalize_sentence_with_original("hello world", "{original} [{normalized}]"),
+            "hello world"
+        );
+        assert_eq!(normalize_sentence_with_original("", "{normalized}"), "");
+    }
+
+    #[test]
+    fn test_fraction_slash() {
+        assert_eq!(normalize("three slash four"), "3/4");
+        // URLs keep their path slashes
+        assert_eq!(normalize("example dot com slash path"), "example.com/path");
+        assert_eq!(
+            normalize_sentence("add three slash four cup of sugar"),
+            "add 3/4 cup of sugar"
+        );
+    }
+
+    #[test]
+    fn test_sentence_reference_numbers() {
+        // Prices after a unit cue stay money
+        assert_eq!(normalize_sentence("each unit five dollars"), "each unit $5");
+        assert_eq!(
+            normalize_sentence("a room two hundred dollars a night"),
+            "a room $200 a night"
+        );
+        assert_eq!(
+            normalize_sentence("please void check number one zero zero five today"),
+            "please void check #1005 today"
+        );
+        assert_eq!(
+            normalize("invoice number two three four five"),
+            "invoice #2345"
+        );
+        assert_eq!(
+            normalize_sentence("I live in apartment four oh two downtown"),
+            "I live in apartment 402 downtown"
+        );
+    }
+
+    #[test]
+    fn test_period_word_sentence_final_only() {
+        let options = NormalizeOptions {
+            period_word_sentence_final_only: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_sentence_with_options("he left period", &options),
+            "he left ."
+        );
+        assert_eq!(
+            normalize_sentence_with_options("the period was long", &options),
+            "the period was long"
+        );
+        assert_eq!(
+            normalize_sentence_with_options("he left period question mark", &options),
+            "he left . ?"
+        );
+        // Symbol words are not punctuation followers
+        assert_eq!(
+            normalize_sentence_with_options("the period plus interest", &options),
+            "the period + interest"
+        );
+        assert_eq!(
+            normalize_sentence_with_options("the period dash then more", &options),
+            "the period - then more"
+        );
+        // Default behavior converts every "period"
+        assert_eq!(normalize_sentence("the period was long"), "the . was long");
+    }
+
+    #[test]
+    fn test_signed_percent_option() {
+        let options = NormalizeOptions {
+            signed_percent: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_sentence_with_options("shares were up five percent today", &options),
+            "shares were +5 % today"
+        );
+        assert_eq!(
+            normalize_sentence("shares were up five percent today"),
+            "shares were up 5 % today"
+        );
+    }
+
+    #[test]
+    fn test_minus_offset_vs_time() {
+        assert_eq!(normalize("minus five minutes"), "-5 min");
+        assert_eq!(normalize("two thirty"), "02:30");
+        assert_eq!(
+            normalize_sentence("the timer shows minus five minutes"),
+            "the timer shows -5 min"
+        );
+        // A signed clock time has no reading and stays as spoken
+        assert_eq!(normalize("minus two thirty"), "minus two thirty");
+        assert_eq!(
+            normalize_sentence("the offset is minus two thirty today"),
+            "the offset is minus two thirty today"
+        );
+    }
+
+    #[test]
+    fn test_military_time_option() {
+        let options = NormalizeOptions {
+            military_time: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_with_options("oh eight hundred hours", &options),
+            "08:00"
+        );
+        assert_eq!(
+            normalize_sentence_with_options("report at twenty three hundred hours sharp", &options),
+            "report at 23:00 sharp"
+        );
+        // Durations stay measurements
+        assert_eq!(
+            normalize_sentence_with_options("we worked a hundred hours", &options),
+            "we worked 100 h"
+        );
+        assert_eq!(
+            normalize_sentence_with_options("depart at oh one hundred hours", &options),
+            "depart at 01:00"
+        );
+
+        // Bare readings are clock times only as a whole input
+        assert_eq!(
+            normalize_with_options("fourteen hundred", &options),
+            "14:00"
+        );
+        assert_eq!(
+            normalize_with_options("zero nine thirty", &options),
+            "09:30"
+        );
+        assert_eq!(
+            normalize_with_options("twenty three hundred", &options),
+            "23:00"
+        );
+        assert_eq!(
+            normalize_with_options("twenty five hundred", &options),
+            "2500"
+        );
+        assert_eq!(
+            normalize_sentence_with_options("fourteen hundred people came", &options),
+            "1400 people came"
+        );
+        assert_eq!(normalize("fourteen hundred"), "1400");
+    }
+
+    #[test]
+    fn test_fuzzy_quantifiers_option() {
+        let options = NormalizeOptions {
+            fuzzy_quantifiers: true,
+            ..Default::default()
+        };
+        assert_eq!(normalize_with_options("a couple hundred", &options), "200");
+        assert_eq!(
+            normalize_sentence_with_options("about a few thousand people came", &options),
+            "about 3000 people came"
+        );
+    }
+
+    #[test]
+    fn test_ordinal_superscript_option() {
+        let options = NormalizeOptions {
+            ordinal_superscript: true,
+            ..Default::default()
+        };
+        assert_eq!(normalize_with_options("twenty first", &options), "21ˢᵗ");
+        assert_eq!(
+            normalize_sentence_with_options("she finished first today", &options),
+            "she finished 1ˢᵗ today"
+        );
+    }
+
+    #[test]
+    fn test_symbol_space_option() {
+        let options = NormalizeOptions {
+            symbol_space: true,
+            ..Default::default()
+        };
+        assert_eq!(normalize_with_options("five dollars", &options), "$ 5");
+        assert_eq!(
+            normalize_sentence_with_options("it costs five dollars today", &options),
+            "it costs $ 5 today"
+        );
+    }
+
+    #[test]
+    fn test_that_is_abbreviation_option() {
+        let options = NormalizeOptions {
+            that_is_abbreviation: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_sentence_with_options("the unit that is twelve eggs", &options),
+            "the unit i.e. 12 eggs"
+        );
+        assert_eq!(
+            normalize_sentence("the unit that is twelve eggs"),
+            "the unit that is 12 eggs"
+        );
+    }
+
+    #[test]
+    fn test_number_prefix_option() {
+        let options = NormalizeOptions {
+            number_prefix: Some(NumberPrefixStyle::Hash),
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_sentence_with_options("take the number five bus", &options),
+            "take the #5 bus"
+        );
+        assert_eq!(
+            normalize_sentence_with_options("a large number of people", &options),
+            "a large number of people"
+        );
+        assert_eq!(
+            normalize_sentence("take the number five bus"),
+            "take the number 5 bus"
+        );
+    }
+
+    #[test]
+    fn test_spell_single_letters_option() {
+        let options = NormalizeOptions {
+            spell_single_letters: true,
+            ..Default::default()
+        };
+        assert_eq!(normalize_with_options("a b c", &options), "abc");
+        assert_eq!(
+            normalize_sentence_with_options("spell it a b c please", &options),
+            "spell it abc please"
+        );
+        assert_eq!(
+            normalize_sentence_with_options("a big house", &options),
+            "a big house"
+        );
+        assert_eq!(normalize_sentence("spell it a b c"), "spell it a b c");
+    }
+
+    #[test]
+    fn test_telephone_in_sentence_option() {
+        let input = "call plus forty four one two three one two three five six seven eight today";
+        let options = NormalizeOptions {
+            telephone_in_sentence: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_sentence_with_options(input, &options),
+            "call +44 123-123-5678 today"
+        );
+        assert_eq!(
+            normalize_sentence_with_options("two plus one is three", &options),
+            normalize_sentence("two plus one is three")
+        );
+    }
+
+    #[test]
+    fn test_short_codes() {
+        assert_eq!(normalize("nine eleven"), "911");
+        assert_eq!(normalize("nine one one"), "911");
+        assert_eq!(normalize("four one one"), "411");
+        assert_eq!(normalize("five eleven"), "05:11");
+    }
+
+    #[test]
+    fn test_separate_phone_numbers() {
+        assert_eq!(normalize("one two three and four five six"), "123 and 456");
+        assert_eq!(
+            normalize("five five five one two three four and five five five one two three five"),
+            "555-1234 and 555-1235"
+        );
+    }
+
+    #[test]
+    fn test_scale_percent_passes_through() {
+        let options = NormalizeOptions {
+            percent_space: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_with_options("one point five percent", &options),
+            "1.5%"
+        );
+        assert_eq!(
+            normalize("one point five million percent"),
+            "one point five million percent"
+        );
+    }
+
+    #[test]
+    fn test_roman_after_cue_option() {
+        let options = NormalizeOptions {
+            roman_after_cue: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_sentence_with_options("he served in world war two as a pilot", &options),
+            "he served in World War II as a pilot"
+        );
+    }
+
+    #[test]
+    fn test_best_effort_option() {
+        assert_eq!(normalize("twenty glorp"), "twenty glorp");
+
+        let options = NormalizeOptions {
+            best_effort: true,
+            ..Default::default()
+        };
+        assert_eq!(normalize_with_options("twenty glorp", &options), "20 glorp");
+        assert_eq!(
+            normalize_with_options("glorp five dollars blip", &options),
+            "glorp $5 blip"
+        );
+        assert_eq!(normalize_with_options("glorp", &options), "glorp");
+        // A full match still wins over the fallback
+        assert_eq!(normalize_with_options("twenty one", &options), "21");
+    }
+
+    #[test]
+    fn test_oh_minute_falls_through() {
+        assert_eq!(normalize("twelve oh five"), "12:05");
+        assert_eq!(normalize("twelve oh fifteen"), "twelve oh fifteen");
+    }
+
+    #[test]
+    fn test_time_before_quantity_cue() {
+        assert_eq!(
+            normalize_sentence("meet at two thirty today"),
+            "meet at 02:30 today"
+        );
+        assert_eq!(
+            normalize_sentence("got two thirty dollar tickets"),
+            "got $230 tickets"
+        );
+        for input in [
+            "got two thirty euro tickets",
+            "a two thirty pound fine",
+            "bought two thirty cent stamps",
+        ] {
+            assert!(!normalize_sentence(input).contains("02:30"), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_incomplete_time_passes_through() {
+        for input in [
+            "half past",
+            "quarter past",
+            "quarter to",
+            "half past thirty",
+        ] {
+            assert_eq!(normalize(input), input);
+        }
+        assert_eq!(
+            normalize_sentence("see you at half past ten and a half"),
+            "see you at 10:30 and a half"
+        );
+    }
+
+    #[test]
+    fn test_list_markers() {
+        assert_eq!(normalize("one a"), "1a");
+        assert_eq!(normalize("two b"), "2b");
+        assert_eq!(normalize("one a penny"), "one a penny");
+        assert_eq!(normalize("five o"), "five o");
+        assert_eq!(normalize("two o"), "two o");
+    }
+
+    #[test]
+    fn test_point_after_currency_passes_through() {
+        assert_eq!(
+            normalize("five dollars point fifty"),
+            "five dollars point fifty"
+        );
+        assert_eq!(
+            normalize_sentence("it cost five dollars point fifty today"),
+            "it cost five dollars point fifty today"
+        );
+        assert_eq!(
+            normalize_sentence("it cost five dollars and fifty cents today"),
+            "it cost $5.50 today"
+        );
+    }
+
+    #[test]
+    fn test_split_edge_punctuation() {
+        assert_eq!(
+            split_edge_punctuation(&["\"twenty", "one,"]),
+            Some(("\"", "twenty one".to_string(), ","))
+        );
+        assert_eq!(
+            split_edge_punctuation(&["one?!"]),
+            Some(("", "one".to_string(), "?!"))
+        );
+        assert_eq!(split_edge_punctuation(&["twenty", "one"]), None);
+        assert_eq!(split_edge_punctuation(&["one,", "two"]), None);
+        assert_eq!(split_edge_punctuation(&["one", ","]), None);
+    }
+
+    #[test]
+    fn test_sentence_spans() {
+        let input = "I paid  five dollars on the third, okay";
+        let spans = normalize_sentence_spans(input);
+        assert_eq!(
+            spans,
+            vec![
+                Replacement {
+                    start: 8,
+                    end: 20,
+                    original: "five dollars".to_string(),
+                    normalized: "$5".to_string(),
+                    tagger: "money",
+                },
+                Replacement {
+                    start: 28,
+                    end: 34,
+                    original: "third,".to_string(),
+                    normalized: "3rd,".to_string(),
+                    tagger: "ordinal",
+                },
+            ]
+        );
+        for span in &spans {
+            assert_eq!(&input[span.start..span.end], span.original);
+        }
+        assert!(normalize_sentence_spans("hello world").is_empty());
+        assert!(normalize_sentence_spans("").is_empty());
+    }
+
+    #[test]
+    fn test_would_normalize() {
+        assert!(would_normalize("twenty one"));
+        assert!(would_normalize("point five percent"));
+        assert!(!would_normalize("hello world"));
+        assert!(!would_normalize("21"));
+        assert!(!would_normalize(""));
+    }
+
+    #[test]
+    fn test_percent_matrix() {
+        let spaced = NormalizeOptions::default();
+        let unspaced = NormalizeOptions {
+            percent_space: false,
+            ..Default::default()
+        };
+        let cases = [
+            ("eighteen point one four percent", "18.14 %", "18.14%"),
+            ("one hundred percent", "100 %", "100%"),
+            ("a hundred percent", "100 %", "100%"),
+            ("half a percent", "0.5 %", "0.5%"),
+            ("point five percent", "0.5 %", "0.5%"),
+            ("minus point five percent", "-0.5 %", "-0.5%"),
+            ("ten to twenty percent", "10-20 %", "10-20%"),
+        ];
+        for (input, with_space, without_space) in cases {
+            assert_eq!(
+                normalize_with_options(input, &spaced),
+                with_space,
+                "{}",
+                input
+            );
+            assert_eq!(
+                normalize_with_options(input, &unspaced),
+                without_space,
+                "{}",
+                input
+            );
+            assert_eq!(
+                normalize_sentence_with_options(&format!("about {} more", input), &unspaced),
+                format!("about {} more", without_space),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_percent_space_option() {
+        assert_eq!(normalize("point five percent"), "0.5 %");
+        let options = NormalizeOptions {
+            percent_space: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_sentence_with_options("rates fell point five percent today", &options),
+            "rates fell 0.5% today"
+        );
+
+        // Every percentage path follows the option
+        let spaced = NormalizeOptions::default();
+        let signed = NormalizeOptions {
+            signed_percent: true,
+            ..options.clone()
+        };
+        let out_of = NormalizeOptions {
+            out_of_hundred_percent: true,
+            ..options.clone()
+        };
+        let cases = [
+            ("eighteen point one four percent", &options, "18.14%"),
+            ("eighteen point one four percent", &spaced, "18.14 %"),
+            ("half a percent", &options, "0.5%"),
+            ("twelve and a half percent", &options, "12.5%"),
+            ("ten to twenty percent", &options, "10-20%"),
+            ("ten to twenty percent", &spaced, "10-20 %"),
+            ("up five percent", &signed, "+5%"),
+            ("ninety out of one hundred", &out_of, "90%"),
+        ];
+        for (input, options, expected) in cases {
+            assert_eq!(
+                normalize_with_options(input, options),
+                expected,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_max_input_tokens_option() {
+        let options = NormalizeOptions {
+            max_input_tokens: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_sentence_with_options("I have twenty one apples", &options),
+            "I have 21 apples"
+        );
+        assert_eq!(
+            normalize_sentence_with_options("I have twenty one  apples and pears", &options),
+            "I have twenty one  apples and pears"
+        );
+        assert_eq!(
+            normalize_sentence("I have twenty one apples and pears"),
+            "I have 21 apples and pears"
+        );
+    }
+
+    #[test]
+    fn test_ordinal_requires_context_option() {
+        let options = NormalizeOptions {
+            ordinal_requires_context: true,
+            ..Default::default()
+        };
+        let sentence = |s: &str| normalize_sentence_with_options(s, &options);
+        assert_eq!(sentence("first let's go"), "first let's go");
+        assert_eq!(sentence("first we eat"), "first we eat");
+        assert_eq!(sentence("he came first"), "he came first");
+        assert_eq!(sentence("the first place"), "the 1st place");
+        assert_eq!(sentence("my first car"), "my 1st car");
+        assert_eq!(sentence("the twenty first century"), "the 21st century");
+
+        assert_eq!(normalize_sentence("first let's go"), "1st let's go");
+        assert_eq!(normalize_sentence("he came first"), "he came 1st");
+    }
+
+    #[test]
+    fn test_british_time_option() {
+        let options = NormalizeOptions {
+            british_time: true,
+            ..Default::default()
+        };
+        assert_eq!(normalize_with_options("half ten", &options), "10:30");
+        assert_eq!(
+            normalize_sentence_with_options("see you at half ten", &options),
+            "see you at 10:30"
+        );
+        assert_eq!(normalize("half ten"), "half ten");
+    }
+
+    #[test]
+    fn test_ellipsis_char_option() {
+        let options = NormalizeOptions {
+            ellipsis_char: true,
+            ..Default::default()
+        };
+        assert_eq!(normalize_with_options("ellipsis", &options), "…");
+        assert_eq!(normalize("ellipsis"), "...");
+        assert_eq!(
+            normalize_sentence_with_options("wait ellipsis what happened", &options),
+            "wait … what happened"
+        );
+        assert_eq!(
+            normalize_sentence("wait ellipsis what happened"),
+            "wait ... what happened"
+        );
+    }
 }
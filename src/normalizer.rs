@@ -0,0 +1,139 @@
+//! Normalizer instances with their own custom rules.
+//!
+//! The free functions ([`crate::normalize`], [`crate::normalize_sentence`])
+//! read the process-wide rules in [`crate::custom_rules`]. A [`Normalizer`]
+//! keeps its rules and options to itself, so callers with different
+//! vocabularies can share a process without seeing each other's rules.
+//!
+//! ```
+//! use nemo_text_processing::Normalizer;
+//!
+//! let mut normalizer = Normalizer::new();
+//! normalizer.add_rule("gee pee tee", "GPT");
+//! assert_eq!(normalizer.normalize("gee pee tee"), "GPT");
+//! assert_eq!(
+//!     normalizer.normalize_sentence("gee pee tee has twenty one layers"),
+//!     "GPT has 21 layers"
+//! );
+//! ```
+
+use crate::{custom_rules, NormalizeOptions, DEFAULT_MAX_SPAN_TOKENS};
+
+/// A normalizer holding its own custom rules and options.
+///
+/// Global rules added with [`custom_rules::add_rule`] are not consulted.
+#[derive(Debug, Clone, Default)]
+pub struct Normalizer {
+    /// Entries are (lowercase_spoken, written), as in the global store.
+    rules: Vec<(String, String)>,
+    options: NormalizeOptions,
+}
+
+impl Normalizer {
+    /// Create a normalizer with no custom rules and default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a normalizer with no custom rules and the given options.
+    pub fn with_options(options: NormalizeOptions) -> Self {
+        Self {
+            rules: Vec::new(),
+            options,
+        }
+    }
+
+    /// Add a custom spoken→written mapping, replacing an existing one for
+    /// the same spoken form. Matching is case-insensitive.
+    pub fn add_rule(&mut self, spoken: &str, written: &str) {
+        custom_rules::insert(&mut self.rules, spoken, written);
+    }
+
+    /// Remove a custom rule by its spoken form.
+    ///
+    /// Returns true if the rule was found and removed.
+    pub fn remove_rule(&mut self, spoken: &str) -> bool {
+        custom_rules::remove(&mut self.rules, spoken)
+    }
+
+    /// Number of custom rules on this normalizer.
+    pub fn rule_count(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// The options this normalizer applies.
+    pub fn options(&self) -> &NormalizeOptions {
+        &self.options
+    }
+
+    /// Normalize a whole input, as [`crate::normalize_with_options`] does.
+    pub fn normalize(&self, input: &str) -> String {
+        crate::normalize_with_rules(input, &self.options, &self.rules)
+    }
+
+    /// Normalize the spans inside a sentence, as
+    /// [`crate::normalize_sentence_with_options`] does.
+    pub fn normalize_sentence(&self, input: &str) -> String {
+        crate::sentence_with_options(input, DEFAULT_MAX_SPAN_TOKENS, &self.options, &self.rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instance_rules() {
+        let mut first = Normalizer::new();
+        first.add_rule("gee pee tee", "GPT");
+        let mut second = Normalizer::new();
+        second.add_rule("gee pee tee", "G.P.T.");
+
+        assert_eq!(first.normalize("Gee Pee Tee"), "GPT");
+        assert_eq!(second.normalize("gee pee tee"), "G.P.T.");
+        assert_eq!(Normalizer::new().normalize("gee pee tee"), "gee pee tee");
+
+        // Rules take priority over built-in taggers
+        first.add_rule("twenty one", "twenty-one");
+        assert_eq!(first.normalize("twenty one"), "twenty-one");
+        assert_eq!(second.normalize("twenty one"), "21");
+    }
+
+    #[test]
+    fn test_instance_rule_updates() {
+        let mut normalizer = Normalizer::new();
+        normalizer.add_rule("linux", "Linux");
+        normalizer.add_rule("Linux", "GNU/Linux");
+        assert_eq!(normalizer.rule_count(), 1);
+        assert_eq!(normalizer.normalize("linux"), "GNU/Linux");
+
+        assert!(normalizer.remove_rule("LINUX"));
+        assert!(!normalizer.remove_rule("linux"));
+        assert_eq!(normalizer.rule_count(), 0);
+        assert_eq!(normalizer.normalize("linux"), "linux");
+    }
+
+    #[test]
+    fn test_instance_sentence() {
+        let mut normalizer = Normalizer::new();
+        normalizer.add_rule("gee pee tee", "GPT");
+        assert_eq!(
+            normalizer.normalize_sentence("I use gee pee tee for twenty one tasks"),
+            "I use GPT for 21 tasks"
+        );
+    }
+
+    #[test]
+    fn test_instance_options() {
+        let normalizer = Normalizer::with_options(NormalizeOptions {
+            ellipsis_char: true,
+            ..Default::default()
+        });
+        assert!(normalizer.options().ellipsis_char);
+        assert_eq!(normalizer.normalize("ellipsis"), "…");
+        assert_eq!(
+            normalizer.normalize_sentence("wait ellipsis what"),
+            "wait … what"
+        );
+    }
+}
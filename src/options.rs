@@ -0,0 +1,206 @@
+//! Normalization options.
+//!
+//! Options adjust how recognized spans are rendered. The defaults match
+//! [`crate::normalize`] and [`crate::normalize_sentence`].
+//!
+//! ```
+//! use nemo_text_processing::{normalize_with_options, NormalizeOptions};
+//!
+//! let options = NormalizeOptions {
+//!     ellipsis_char: true,
+//!     ..Default::default()
+//! };
+//! assert_eq!(normalize_with_options("ellipsis", &options), "…");
+//! ```
+
+/// Options controlling normalization output.
+#[derive(Debug, Clone)]
+pub struct NormalizeOptions {
+    /// Render "ellipsis" as the single character "…" instead of "...".
+    pub ellipsis_char: bool,
+
+    /// In sentence mode, only convert the word "period" to "." when it ends
+    /// the input or is followed by another spoken punctuation word.
+    /// Reduces false positives like "the period was long".
+    pub period_word_sentence_final_only: bool,
+
+    /// Render percentage changes with a sign: "up five percent" → "+5 %",
+    /// "down three percent" → "-3 %".
+    pub signed_percent: bool,
+
+    /// Read "<HHMM> hours" as 24-hour clock time: "oh eight hundred hours"
+    /// → "08:00", "fourteen thirty hours" → "14:30". A whole input may drop
+    /// "hours": "fourteen hundred" → "14:00".
+    pub military_time: bool,
+
+    /// Read vague quantities before a scale as round numbers:
+    /// "a couple hundred" → "200", "a few thousand" → "3000".
+    pub fuzzy_quantifiers: bool,
+
+    /// Value of "couple" under `fuzzy_quantifiers` (default 2).
+    pub fuzzy_couple: i128,
+
+    /// Value of "few" under `fuzzy_quantifiers` (default 3). `None` leaves
+    /// "few" as words, since it has no agreed value.
+    pub fuzzy_few: Option<i128>,
+
+    /// Render ordinal suffixes as Unicode superscripts: "first" → "1ˢᵗ",
+    /// "twenty first" → "21ˢᵗ".
+    pub ordinal_superscript: bool,
+
+    /// In sentence mode, only convert an ordinal after "the", "a" or a
+    /// number, or before a word that could start a noun phrase: "the first
+    /// place" → "the 1st place", but "first let's go" stays. A word-list
+    /// heuristic, so some adverbs still convert ("second thoughts" →
+    /// "2nd thoughts") and some adjectives don't ("she came in third").
+    pub ordinal_requires_context: bool,
+
+    /// Render "X out of Y" as a ratio ("one out of four" → "1 out of 4")
+    /// instead of a fraction ("1/4").
+    pub out_of_ratio: bool,
+
+    /// Insert a space between a currency symbol and the amount:
+    /// "five dollars" → "$ 5".
+    pub symbol_space: bool,
+
+    /// Abbreviate "that is" to "i.e.". Off by default since "that is" is
+    /// usually ordinary prose ("that is correct").
+    pub that_is_abbreviation: bool,
+
+    /// Recognize British imperial units: "eleven stone four" → "11 st 4 lb".
+    pub british_units: bool,
+
+    /// Read British "half <hour>" as half past the hour: "half ten" →
+    /// "10:30". Off by default since elsewhere "half ten" is ambiguous.
+    pub british_time: bool,
+
+    /// Render play acts as Roman numerals: "act two scene three" →
+    /// "Act II Scene 3" instead of "act 2 scene 3".
+    pub roman_acts: bool,
+
+    /// Title-case enumeration cues, as in numbered instructions: "step one"
+    /// → "Step 1" instead of "step 1".
+    pub title_case_steps: bool,
+
+    /// Put a space before "%": "18.14 %" (default, as in the NeMo test
+    /// data). Set to false for "18.14%". Applies to every percentage,
+    /// including signed changes, ranges and "out of one hundred".
+    pub percent_space: bool,
+
+    /// Separator between range endpoints: "10-20" (default '-'). Set to
+    /// '–' for an en dash.
+    pub range_separator: char,
+
+    /// Write "number five" as "#5" or "No. 5". Off by default since
+    /// "number" is a common word.
+    pub number_prefix: Option<NumberPrefixStyle>,
+
+    /// Join runs of two or more spelled single letters: "a b c" → "abc".
+    /// A lone letter is left alone, so the article in "a big house" stays.
+    pub spell_single_letters: bool,
+
+    /// In sentence mode, recognize international phone numbers led by
+    /// "plus" ("call plus forty four ..."). Other phone numbers stay out of
+    /// sentences, where digit runs are usually something else.
+    pub telephone_in_sentence: bool,
+
+    /// Write numbers after event cues as Roman numerals: "super bowl fifty
+    /// eight" → "Super Bowl LVIII", "world war two" → "World War II".
+    pub roman_after_cue: bool,
+
+    /// Write "ninety out of one hundred" as a percentage ("90 %") instead of
+    /// "90/100". Spacing follows `percent_space`.
+    pub out_of_hundred_percent: bool,
+
+    /// When no tagger matches the whole input, convert the spans inside it
+    /// as sentence mode would: "twenty glorp" → "20 glorp". Off by default,
+    /// so `normalize` returns unmatched input unchanged.
+    pub best_effort: bool,
+
+    /// Read "five twenty five dollars" as dollars and cents ("$5.25")
+    /// rather than the default "$525", which follows the NeMo grammar
+    /// ("one fifty five dollars" → "$155").
+    pub shorthand_cents: bool,
+
+    /// Scale for "degrees" spoken without one: "thirty seven point five
+    /// degrees" → "37.5 °C". Off by default, leaving bare degrees as words.
+    pub default_temperature_scale: Option<TemperatureScale>,
+
+    /// Add "°" after a latitude or longitude: "latitude minus thirty three
+    /// point eight six eight" → "latitude -33.868°". A spoken "degrees"
+    /// always gets the symbol.
+    pub coordinate_degrees: bool,
+
+    /// Longest sentence, in whitespace tokens, that sentence mode will
+    /// search. Longer input is returned unchanged, apart from trimming, so
+    /// huge transcripts can't stall a caller; split them before
+    /// normalizing instead. `None` (default) means no limit.
+    pub max_input_tokens: Option<usize>,
+}
+
+/// Written form for a spoken "number" prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumberPrefixStyle {
+    /// "number five" → "#5"
+    Hash,
+    /// "number five" → "No. 5"
+    Abbreviation,
+}
+
+/// Temperature scale assumed for bare "degrees".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemperatureScale {
+    /// "37.5°"
+    Unspecified,
+    /// "37.5 °C"
+    Celsius,
+    /// "37.5 °F"
+    Fahrenheit,
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        Self {
+            ellipsis_char: false,
+            period_word_sentence_final_only: false,
+            signed_percent: false,
+            military_time: false,
+            fuzzy_quantifiers: false,
+            fuzzy_couple: 2,
+            fuzzy_few: Some(3),
+            ordinal_superscript: false,
+            ordinal_requires_context: false,
+            out_of_ratio: false,
+            symbol_space: false,
+            that_is_abbreviation: false,
+            british_units: false,
+            british_time: false,
+            roman_acts: false,
+            title_case_steps: false,
+            percent_space: true,
+            range_separator: '-',
+            number_prefix: None,
+            spell_single_letters: false,
+            telephone_in_sentence: false,
+            roman_after_cue: false,
+            out_of_hundred_percent: false,
+            best_effort: false,
+            shorthand_cents: false,
+            default_temperature_scale: None,
+            coordinate_degrees: false,
+            max_input_tokens: None,
+        }
+    }
+}
+
+impl NormalizeOptions {
+    /// The percent sign with its leading space, per `percent_space`.
+    #[cfg(any(feature = "measure", feature = "fraction"))]
+    pub(crate) fn percent_sign(&self) -> &'static str {
+        if self.percent_space {
+            " %"
+        } else {
+            "%"
+        }
+    }
+}
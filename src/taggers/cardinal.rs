@@ -6,7 +6,10 @@
 //! - "one hundred twenty three" → "123"
 //! - "one thousand two hundred thirty four" → "1234"
 //! - "minus sixty" → "-60"
+//! - "a couple hundred" → "200" (fuzzy quantifiers option)
+//! - "two dozen" → "24"
 
+use crate::NormalizeOptions;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
@@ -73,6 +76,17 @@ lazy_static! {
 ///
 /// Returns None if the input cannot be parsed as a number.
 pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Parse spoken cardinal number, applying output options.
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
+    if options.fuzzy_quantifiers {
+        if let Some(num) = parse_fuzzy_quantifier(input, options) {
+            return Some(num.to_string());
+        }
+    }
+
     let input = input.to_lowercase();
     let input = input.trim();
 
@@ -81,22 +95,42 @@ pub fn parse(input: &str) -> Option<String> {
         return Some("zero".to_string());
     }
 
-    // Check for negative
-    let (is_negative, rest) = if input.starts_with("minus ") {
-        (true, input.strip_prefix("minus ")?)
-    } else if input.starts_with("negative ") {
-        (true, input.strip_prefix("negative ")?)
-    } else {
-        (false, input)
-    };
-
+    let (sign, rest) = split_sign(input);
     let num = words_to_number(rest)?;
+    Some(format!("{}{}", sign, num))
+}
 
-    if is_negative {
-        Some(format!("-{}", num))
-    } else {
-        Some(num.to_string())
+/// Split a spoken sign from the value: "minus five" → ("-", "five").
+/// Unsigned input gives ("", input). Shared by the number taggers so a
+/// sign reads the same on integers, decimals, money and measures.
+pub fn split_sign(input: &str) -> (&'static str, &str) {
+    for prefix in ["minus ", "negative "] {
+        if let Some(rest) = input.strip_prefix(prefix) {
+            return ("-", rest);
+        }
+    }
+    ("", input)
+}
+
+/// Parse a vague quantity before a scale as a round number:
+/// "a couple hundred" → 200, "a few thousand" → 3000.
+fn parse_fuzzy_quantifier(input: &str, options: &NormalizeOptions) -> Option<i128> {
+    let input = input.trim().to_lowercase();
+    let input = input.strip_prefix("a ").unwrap_or(&input);
+    let (quantifier, scales) = input.split_once(' ')?;
+
+    let multiplier = match quantifier {
+        "couple" => options.fuzzy_couple,
+        "few" => options.fuzzy_few?,
+        _ => return None,
+    };
+
+    // Only bare scale words may follow: "hundred", "hundred thousand"
+    if !scales.split_whitespace().all(|w| SCALES.contains_key(w)) {
+        return None;
     }
+    let scale = words_to_number(&format!("one {}", scales))?;
+    multiplier.checked_mul(scale)
 }
 
 /// Convert spoken number words to integer.
@@ -113,6 +147,21 @@ pub fn parse(input: &str) -> Option<String> {
 /// - "one thousand two hundred thirty four" → (1 * 1000) + (2 * 100) + 30 + 4 = 1234
 pub fn words_to_number(input: &str) -> Option<i128> {
     let input = input.to_lowercase();
+
+    // Connectors only make sense between number words ("one hundred and one")
+    // or before a scale ("a hundred"). A dangling "and"/"a" belongs to the
+    // surrounding sentence, e.g. "nineteen ninety and more".
+    let raw_words: Vec<&str> = input.split_whitespace().collect();
+    if matches!(raw_words.first(), Some(&"and"))
+        || matches!(raw_words.last(), Some(&"and") | Some(&"a"))
+    {
+        return None;
+    }
+    if raw_words.first() == Some(&"a") && !raw_words.get(1).is_some_and(|w| SCALES.contains_key(w))
+    {
+        return None;
+    }
+
     let words: Vec<&str> = input
         .split_whitespace()
         .filter(|w| *w != "and" && *w != "a")
@@ -164,30 +213,42 @@ pub fn words_to_number(input: &str) -> Option<i128> {
     let mut result: i128 = 0;
     let mut current: i128 = 0;
     let mut found_number = false;
+    // Scale of the previous word, to reject malformed runs like "million million"
+    let mut prev_scale: Option<i128> = None;
 
     for word in words {
         if let Some(&val) = ONES.get(word) {
             current += val as i128;
             found_number = true;
+            prev_scale = None;
         } else if let Some(&val) = TENS.get(word) {
             current += val as i128;
             found_number = true;
-        } else if word == "hundred" {
+            prev_scale = None;
+        } else if let Some(&scale) = SCALES.get(word) {
+            // A scale directly after a larger-or-equal scale has no multiplier
+            if prev_scale.is_some_and(|prev| scale <= prev) {
+                return None;
+            }
+            prev_scale = Some(scale);
+
             if current == 0 {
                 current = 1;
             }
-            current *= 100;
-            found_number = true;
-        } else if let Some(&scale) = SCALES.get(word) {
+            current = current.checked_mul(scale)?;
             if scale >= 1000 {
-                if current == 0 {
-                    current = 1;
-                }
-                current *= scale;
-                result += current;
+                result = result.checked_add(current)?;
                 current = 0;
-                found_number = true;
             }
+            found_number = true;
+        } else if let Some(group) = group_value(word) {
+            // "two dozen", "three score": the count must be spoken, and
+            // plurals ("dozens of people") are vague and never match
+            if current == 0 || prev_scale.is_some() {
+                return None;
+            }
+            prev_scale = Some(group);
+            current = current.checked_mul(group)?;
         } else {
             // Unknown word - not a valid number
             return None;
@@ -201,10 +262,76 @@ pub fn words_to_number(input: &str) -> Option<i128> {
     }
 }
 
+/// Counting groups that multiply a preceding number
+fn group_value(word: &str) -> Option<i128> {
+    match word {
+        "dozen" => Some(12),
+        "score" => Some(20),
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_dozen_and_score() {
+        assert_eq!(parse("two dozen"), Some("24".to_string()));
+        assert_eq!(parse("three score"), Some("60".to_string()));
+        assert_eq!(parse("three score and ten"), Some("70".to_string()));
+        assert_eq!(parse("dozen"), None);
+        assert_eq!(parse("dozens"), None);
+        assert_eq!(parse("two dozens"), None);
+        assert_eq!(parse("the score"), None);
+        assert_eq!(parse("two dozen dozen"), None);
+    }
+
+    #[test]
+    fn test_fuzzy_quantifiers() {
+        let options = NormalizeOptions {
+            fuzzy_quantifiers: true,
+            ..Default::default()
+        };
+        let parse_fuzzy = |s: &str| parse_with_options(s, &options);
+        assert_eq!(parse_fuzzy("a couple hundred"), Some("200".to_string()));
+        assert_eq!(parse_fuzzy("a few thousand"), Some("3000".to_string()));
+        assert_eq!(
+            parse_fuzzy("a couple hundred thousand"),
+            Some("200000".to_string())
+        );
+        assert_eq!(parse_fuzzy("a couple"), None);
+        assert_eq!(parse_fuzzy("a couple people"), None);
+        // Off by default
+        assert_eq!(parse("a couple hundred"), None);
+
+        let keep_few = NormalizeOptions {
+            fuzzy_quantifiers: true,
+            fuzzy_few: None,
+            ..Default::default()
+        };
+        assert_eq!(parse_with_options("a few thousand", &keep_few), None);
+        assert_eq!(
+            parse_with_options("a couple hundred", &keep_few),
+            Some("200".to_string())
+        );
+
+        let custom = NormalizeOptions {
+            fuzzy_quantifiers: true,
+            fuzzy_couple: 3,
+            fuzzy_few: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("a couple hundred", &custom),
+            Some("300".to_string())
+        );
+        assert_eq!(
+            parse_with_options("a few thousand", &custom),
+            Some("5000".to_string())
+        );
+    }
+
     #[test]
     fn test_ones() {
         assert_eq!(parse("one"), Some("1".to_string()));
@@ -255,6 +382,15 @@ mod tests {
         assert_eq!(parse("two million three"), Some("2000003".to_string()));
     }
 
+    #[test]
+    fn test_malformed_scales() {
+        assert_eq!(words_to_number("million million"), None);
+        assert_eq!(words_to_number("one thousand hundred"), None);
+        assert_eq!(parse("million million"), None);
+        assert_eq!(parse("two million"), Some("2000000".to_string()));
+        assert_eq!(parse("five hundred thousand"), Some("500000".to_string()));
+    }
+
     #[test]
     fn test_negative() {
         assert_eq!(parse("minus sixty"), Some("-60".to_string()));
@@ -262,6 +398,24 @@ mod tests {
             parse("minus twenty five thousand thirty seven"),
             Some("-25037".to_string())
         );
+        assert_eq!(parse("minus five"), Some("-5".to_string()));
+        assert_eq!(parse("negative five"), Some("-5".to_string()));
+    }
+
+    #[test]
+    fn test_split_sign() {
+        assert_eq!(split_sign("minus five"), ("-", "five"));
+        assert_eq!(split_sign("negative five"), ("-", "five"));
+        assert_eq!(split_sign("five"), ("", "five"));
+    }
+
+    #[test]
+    fn test_dangling_connectors() {
+        assert_eq!(parse("a hundred"), Some("100".to_string()));
+        assert_eq!(parse("twenty and"), None);
+        assert_eq!(parse("and five"), None);
+        assert_eq!(parse("twenty a"), None);
+        assert_eq!(parse("a five"), None);
     }
 
     #[test]
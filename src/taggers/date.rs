@@ -456,6 +456,10 @@ fn parse_year_number(input: &str) -> Option<i64> {
                     .strip_prefix("oh ")
                     .or_else(|| year_part.strip_prefix("o "))?;
                 let digit = words_to_number(digit_part)? as i64;
+                // "oh" only pads a single digit ("twelve oh fifteen" is no year)
+                if !(1..=9).contains(&digit) {
+                    return None;
+                }
                 return Some(c * 100 + digit);
             }
 
@@ -517,6 +521,8 @@ mod tests {
         assert_eq!(parse("two thousand and twenty"), Some("2020".to_string()));
         assert_eq!(parse("nineteen ninety four"), Some("1994".to_string()));
         assert_eq!(parse("twenty twelve"), Some("2012".to_string()));
+        assert_eq!(parse("nineteen oh five"), Some("1905".to_string()));
+        assert_eq!(parse("twelve oh fifteen"), None);
     }
 
     #[test]
@@ -5,11 +5,16 @@
 //! - "zero point five" → "0.5"
 //! - "five point two million" → "5.2 million"
 //! - "point five" → ".5"
+//! - "three decimal one four" → "3.14"
+//! - "ten and a half" → "10.5" (via `parse_mixed_number`, for cue-driven callers)
 
-use super::cardinal::words_to_number;
+use std::borrow::Cow;
+
+use super::cardinal::{split_sign, words_to_number};
 
 /// Parse spoken decimal expression to written form.
 pub fn parse(input: &str) -> Option<String> {
+    let input = decimal_word_as_point(input);
     let original = input.trim();
     let input_lower = original.to_lowercase();
 
@@ -26,6 +31,43 @@ pub fn parse(input: &str) -> Option<String> {
     None
 }
 
+/// Whether the input is a decimal followed by "hundred": "three point five
+/// hundred". Unlike "million", "hundred" is not spoken after a decimal, so
+/// the phrase is malformed and is best kept as spoken rather than read as
+/// "3.5" and "100".
+pub fn is_decimal_before_hundred(input: &str) -> bool {
+    let input = input.trim().to_lowercase();
+    input
+        .strip_suffix(" hundred")
+        .is_some_and(|rest| rest.split_whitespace().any(|w| w == "point") && parse(rest).is_some())
+}
+
+/// Read a spoken "decimal" separator as "point": "three decimal one four"
+/// → "three point one four". Only a "decimal" with number words on both
+/// sides is replaced, so prose like "a decimal number" is left alone.
+pub fn decimal_word_as_point(input: &str) -> Cow<'_, str> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let is_number = |w: &str| {
+        let w = w.to_lowercase();
+        w == "o" || w == "oh" || words_to_number(&w).is_some()
+    };
+    let separator_at = |i: usize| {
+        words[i].eq_ignore_ascii_case("decimal")
+            && i > 0
+            && i + 1 < words.len()
+            && is_number(words[i - 1])
+            && is_number(words[i + 1])
+    };
+
+    if !(0..words.len()).any(separator_at) {
+        return Cow::Borrowed(input);
+    }
+    let replaced: Vec<&str> = (0..words.len())
+        .map(|i| if separator_at(i) { "point" } else { words[i] })
+        .collect();
+    Cow::Owned(replaced.join(" "))
+}
+
 /// Parse numbers with scale words (million, billion, trillion)
 fn parse_with_scale(original: &str, input_lower: &str) -> Option<String> {
     let scales = ["trillion", "billion", "million", "thousand"];
@@ -43,7 +85,9 @@ fn parse_with_scale(original: &str, input_lower: &str) -> Option<String> {
                 return Some(format!("{} {}", decimal, orig_scale));
             }
 
-            // Plain number with scale
+            // Plain number with scale; the whole phrase must be a well-formed
+            // cardinal so "million million" is not read as "1000000 million"
+            words_to_number(input_lower)?;
             let num = words_to_number(num_part)? as i64;
             return Some(format!("{} {}", num, orig_scale));
         }
@@ -54,14 +98,7 @@ fn parse_with_scale(original: &str, input_lower: &str) -> Option<String> {
 
 /// Parse "X point Y" decimal pattern
 fn parse_point_decimal(input: &str) -> Option<String> {
-    // Handle negative
-    let (is_negative, rest) = if input.starts_with("minus ") {
-        (true, input.strip_prefix("minus ")?)
-    } else if input.starts_with("negative ") {
-        (true, input.strip_prefix("negative ")?)
-    } else {
-        (false, input)
-    };
+    let (sign, rest) = split_sign(input);
 
     // Handle "point X" (no integer part, e.g., "point five" → ".5")
     let (integer_str, decimal_str) = if rest.starts_with("point ") {
@@ -86,8 +123,6 @@ fn parse_point_decimal(input: &str) -> Option<String> {
     // Decimal part - parse as individual digits
     let decimal_part = parse_decimal_digits(decimal_str)?;
 
-    let sign = if is_negative { "-" } else { "" };
-
     if integer_part.is_empty() {
         Some(format!("{}.{}", sign, decimal_part))
     } else {
@@ -95,6 +130,63 @@ fn parse_point_decimal(input: &str) -> Option<String> {
     }
 }
 
+/// Parse a whole number with a spoken fraction as a decimal:
+/// "ten and a half" → "10.5", "two and a quarter" → "2.25".
+///
+/// Not part of `parse`, since on its own "two and a half" reads better as a
+/// fraction; callers with a cue ("size", a unit) use it directly.
+pub fn parse_mixed_number(input: &str) -> Option<String> {
+    let input = input.trim().to_lowercase();
+    let (whole, fraction) = input.split_once(" and ")?;
+    let fraction_digits = match fraction {
+        "a half" | "one half" => "5",
+        "a quarter" | "one quarter" => "25",
+        "three quarters" => "75",
+        _ => return None,
+    };
+    let whole = words_to_number(whole)?;
+    Some(format!("{}.{}", whole, fraction_digits))
+}
+
+/// Parse a spoken fraction into (numerator, denominator):
+/// "half" → (1, 2), "a tenth" → (1, 10), "three quarters" → (3, 4)
+pub fn parse_fraction_words(input: &str) -> Option<(i64, i64)> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let (numerator, denom_word) = match words.as_slice() {
+        [denom] => (1, *denom),
+        ["a", denom] => (1, *denom),
+        [num @ .., denom] => (
+            i64::try_from(words_to_number(&num.join(" "))?).ok()?,
+            *denom,
+        ),
+        [] => return None,
+    };
+
+    let &(_, _, denominator) = DENOMINATORS
+        .iter()
+        .find(|(singular, plural, _)| denom_word == *singular || denom_word == *plural)?;
+
+    Some((numerator, denominator))
+}
+
+/// Spoken denominators: (singular, plural, value). Shared with the fraction
+/// tagger.
+pub(crate) const DENOMINATORS: [(&str, &str, i64); 13] = [
+    ("half", "halves", 2),
+    ("third", "thirds", 3),
+    ("quarter", "quarters", 4),
+    ("fourth", "fourths", 4),
+    ("fifth", "fifths", 5),
+    ("sixth", "sixths", 6),
+    ("seventh", "sevenths", 7),
+    ("eighth", "eighths", 8),
+    ("ninth", "ninths", 9),
+    ("tenth", "tenths", 10),
+    ("sixteenth", "sixteenths", 16),
+    ("hundredth", "hundredths", 100),
+    ("thousandth", "thousandths", 1000),
+];
+
 /// Parse decimal digits: "one four" → "14", "o five" → "05"
 fn parse_decimal_digits(input: &str) -> Option<String> {
     let words: Vec<&str> = input.split_whitespace().collect();
@@ -114,8 +206,9 @@ fn parse_decimal_digits(input: &str) -> Option<String> {
             "nine" => '9',
             // Handle compound numbers like "twenty six" → "26"
             _ => {
-                // Try to parse as a number
-                if let Some(num) = words_to_number(word) {
+                // Try to parse as a number; scale words are not digits, so
+                // "three point five hundred" is rejected rather than "3.5100"
+                if let Some(num) = words_to_number(word).filter(|&n| n < 100) {
                     for c in (num as i64).to_string().chars() {
                         result.push(c);
                     }
@@ -145,6 +238,19 @@ mod tests {
         assert_eq!(parse("zero point two six"), Some("0.26".to_string()));
     }
 
+    #[test]
+    fn test_decimal_word() {
+        assert_eq!(parse("three decimal one four"), Some("3.14".to_string()));
+        assert_eq!(parse("zero decimal o five"), Some("0.05".to_string()));
+        assert_eq!(parse("minus two decimal five"), Some("-2.5".to_string()));
+        assert_eq!(parse("decimal five"), None);
+        assert_eq!(parse("three decimal"), None);
+        assert_eq!(
+            decimal_word_as_point("a decimal number"),
+            "a decimal number"
+        );
+    }
+
     #[test]
     fn test_point_only() {
         assert_eq!(parse("point five"), Some(".5".to_string()));
@@ -157,12 +263,76 @@ mod tests {
         assert_eq!(parse("eighteen point o o o"), Some("18.000".to_string()));
     }
 
+    #[test]
+    fn test_scale_integer_part() {
+        assert_eq!(
+            parse("five hundred point two five"),
+            Some("500.25".to_string())
+        );
+        assert_eq!(parse("one thousand point one"), Some("1000.1".to_string()));
+        assert_eq!(
+            parse("two thousand three hundred point o five"),
+            Some("2300.05".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rejects_scale_in_digits() {
+        assert_eq!(parse("three point five hundred"), None);
+        assert_eq!(parse("point two thousand one"), None);
+        assert_eq!(parse("three point twenty"), Some("3.20".to_string()));
+
+        assert!(is_decimal_before_hundred("three point five hundred"));
+        assert!(!is_decimal_before_hundred("three hundred"));
+        assert!(!is_decimal_before_hundred("three point five thousand"));
+    }
+
+    #[test]
+    fn test_mixed_zero_and_oh() {
+        assert_eq!(parse("zero point o five"), Some("0.05".to_string()));
+        assert_eq!(parse("zero point zero o five"), Some("0.005".to_string()));
+        assert_eq!(parse("zero point oh five"), Some("0.05".to_string()));
+        assert_eq!(parse("zero point five o"), Some("0.50".to_string()));
+    }
+
+    #[test]
+    fn test_trailing_zeros() {
+        // Trailing zeros are significant and must survive
+        assert_eq!(parse("three point one zero"), Some("3.10".to_string()));
+        assert_eq!(parse("five point zero zero"), Some("5.00".to_string()));
+        assert_eq!(parse("point five o"), Some(".50".to_string()));
+        assert_eq!(
+            parse("three point one zero million"),
+            Some("3.10 million".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mixed_number() {
+        assert_eq!(
+            parse_mixed_number("ten and a half"),
+            Some("10.5".to_string())
+        );
+        assert_eq!(
+            parse_mixed_number("two and a quarter"),
+            Some("2.25".to_string())
+        );
+        assert_eq!(
+            parse_mixed_number("one hundred and three quarters"),
+            Some("100.75".to_string())
+        );
+        assert_eq!(parse_mixed_number("ten and a third"), None);
+        assert_eq!(parse_mixed_number("rock and a half"), None);
+    }
+
     #[test]
     fn test_negative() {
         assert_eq!(
             parse("minus sixty point two four zero zero"),
             Some("-60.2400".to_string())
         );
+        assert_eq!(parse("minus five point five"), Some("-5.5".to_string()));
+        assert_eq!(parse("negative five point five"), Some("-5.5".to_string()));
     }
 
     #[test]
@@ -177,4 +347,10 @@ mod tests {
             Some("4.85 billion".to_string())
         );
     }
+
+    #[test]
+    fn test_malformed_scale() {
+        assert_eq!(parse("million million"), None);
+        assert_eq!(parse("two million"), Some("2 million".to_string()));
+    }
 }
@@ -4,6 +4,7 @@
 //! - "a at gmail dot com" → "a@gmail.com"
 //! - "w w w dot example dot com" → "www.example.com"
 //! - "h t t p colon slash slash..." → "http://..."
+//! - "u dot s dot a" → "U.S.A." (dotted initials, not a domain)
 
 /// Parse spoken electronic address to written form.
 pub fn parse(input: &str) -> Option<String> {
@@ -20,6 +21,11 @@ pub fn parse(input: &str) -> Option<String> {
         return Some(result);
     }
 
+    // Try dotted initials before domains, which they resemble
+    if let Some(result) = parse_initials(original, &input_lower) {
+        return Some(result);
+    }
+
     // Try domain pattern
     if let Some(result) = parse_domain(&input_lower) {
         return Some(result);
@@ -61,13 +67,18 @@ fn parse_email(original: &str, input: &str) -> Option<String> {
     Some(format!("{}@{}", local_part, domain_part))
 }
 
-/// Parse email local part preserving original casing
+/// Parse email local part preserving original casing.
+///
+/// "o"/"oh" can be the letter O or the digit 0. They read as 0 only in a
+/// clearly numeric run, i.e. next to another digit word ("zero oh seven");
+/// otherwise they stay a letter ("j o h n" → "john").
 fn parse_email_part_with_case(original: &str, _input: &str) -> String {
     let mut result = String::new();
     let words: Vec<&str> = original.split_whitespace().collect();
+    let lower: Vec<String> = words.iter().map(|w| w.to_lowercase()).collect();
 
     for (i, word) in words.iter().enumerate() {
-        let word_lower = word.to_lowercase();
+        let word_lower = &lower[i];
         // "dot" at the start should be literal "dot", not "."
         // e.g., "dot three at gmail dot com" → "dot 3@gmail.com"
         if word_lower == "dot" && i == 0 {
@@ -79,7 +90,10 @@ fn parse_email_part_with_case(original: &str, _input: &str) -> String {
             result.push('_');
         } else if word_lower == "dash" || word_lower == "hyphen" {
             result.push('-');
-        } else if let Some(digit) = word_to_digit(&word_lower) {
+        } else if is_letter_or_zero(word_lower) && !in_numeric_run(&lower, i) {
+            // Letter O - preserve original case
+            result.push_str(&word[..1]);
+        } else if let Some(digit) = word_to_digit(word_lower) {
             // Number word - convert to digit
             result.push(digit);
         } else if word.len() == 1 {
@@ -93,6 +107,27 @@ fn parse_email_part_with_case(original: &str, _input: &str) -> String {
     result
 }
 
+/// Words that may be spoken for either the letter O or the digit 0
+fn is_letter_or_zero(word: &str) -> bool {
+    word == "o" || word == "oh"
+}
+
+/// Check whether the "o"/"oh" run containing `i` borders a digit word.
+fn in_numeric_run(words: &[String], i: usize) -> bool {
+    let start = (0..i)
+        .rev()
+        .take_while(|&j| is_letter_or_zero(&words[j]))
+        .last()
+        .unwrap_or(i);
+    let end = (i + 1..words.len())
+        .take_while(|&j| is_letter_or_zero(&words[j]))
+        .last()
+        .unwrap_or(i);
+
+    let is_digit = |j: usize| word_to_digit(&words[j]).is_some();
+    (start > 0 && is_digit(start - 1)) || (end + 1 < words.len() && is_digit(end + 1))
+}
+
 /// Convert word to single digit
 fn word_to_digit(word: &str) -> Option<char> {
     match word {
@@ -138,6 +173,43 @@ fn parse_url(input: &str) -> Option<String> {
     None
 }
 
+/// Top-level domains that end a spoken domain rather than a run of initials
+const COMMON_TLDS: [&str; 12] = [
+    "com", "org", "net", "edu", "gov", "io", "co", "uk", "us", "ai", "dev", "app",
+];
+
+/// Parse dotted initials: "u dot s dot a" → "U.S.A.", "p dot o dot box" →
+/// "P.O. box". At least two single letters; one trailing word is allowed
+/// unless it is a TLD ("a dot b dot com" is a domain).
+fn parse_initials(original: &str, input: &str) -> Option<String> {
+    let labels: Vec<&str> = input.split(" dot ").collect();
+    let (letters, trailing) = match labels.split_last()? {
+        (last, _) if is_single_letter(last) => (labels.as_slice(), None),
+        (last, rest) => (rest, Some(*last)),
+    };
+    if letters.len() < 2 || !letters.iter().all(|l| is_single_letter(l)) {
+        return None;
+    }
+
+    let mut result: String = letters
+        .iter()
+        .map(|l| format!("{}.", l.to_uppercase()))
+        .collect();
+    if let Some(word) = trailing {
+        if word.contains(' ') || COMMON_TLDS.contains(&word) || word_to_char(word).is_some() {
+            return None;
+        }
+        let orig_word = original.get(original.len() - word.len()..)?;
+        result = format!("{} {}", result, orig_word);
+    }
+    Some(result)
+}
+
+/// Check whether a spoken label is a single ASCII letter
+fn is_single_letter(word: &str) -> bool {
+    word.len() == 1 && word.chars().all(|c| c.is_ascii_alphabetic())
+}
+
 /// Parse standalone domain
 fn parse_domain(input: &str) -> Option<String> {
     // Must contain " dot " to be a domain
@@ -145,6 +217,23 @@ fn parse_domain(input: &str) -> Option<String> {
         return None;
     }
 
+    // A spoken label is one word or spelled letters ("n vidia dot com");
+    // several full words in a label are surrounding prose
+    // ("built with dot net framework")
+    if has_prose_label(input) {
+        return None;
+    }
+
+    // Single-letter labels without a TLD are initials followed by prose
+    // ("the u dot s dot a today"), not a domain
+    let labels: Vec<&str> = input.split(" dot ").collect();
+    let (last, rest) = labels.split_last()?;
+    if rest.iter().all(|l| is_single_letter(l))
+        && !COMMON_TLDS.contains(&last.split_whitespace().next()?)
+    {
+        return None;
+    }
+
     let result = parse_domain_part(input);
 
     // Must have at least one dot
@@ -155,6 +244,33 @@ fn parse_domain(input: &str) -> Option<String> {
     }
 }
 
+/// Check whether any domain label contains more than one full word, or is a
+/// function word ("built with dot net")
+fn has_prose_label(input: &str) -> bool {
+    let mut words_in_label = 0;
+    for word in input.split_whitespace() {
+        if matches!(word, "dot" | "slash" | "colon" | "hyphen" | "dash") {
+            words_in_label = 0;
+        } else if is_function_word(word) {
+            return true;
+        } else if word_to_char(word).is_none() {
+            words_in_label += 1;
+            if words_in_label > 1 {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Common function words that never form a spoken domain label
+fn is_function_word(word: &str) -> bool {
+    matches!(
+        word,
+        "the" | "with" | "on" | "in" | "to" | "for" | "of" | "and" | "or" | "from" | "by" | "using"
+    )
+}
+
 /// Parse email local part (before @)
 fn parse_email_part(input: &str) -> String {
     let words: Vec<&str> = input.split_whitespace().collect();
@@ -252,6 +368,23 @@ mod tests {
             parse("a dot b c at gmail dot com"),
             Some("a.bc@gmail.com".to_string())
         );
+        assert_eq!(
+            parse("john dot smith at gmail dot com"),
+            Some("john.smith@gmail.com".to_string())
+        );
+        assert_eq!(
+            parse("john dot a dot smith at gmail dot com"),
+            Some("john.a.smith@gmail.com".to_string())
+        );
+        assert_eq!(
+            parse("john underscore smith dot jr at gmail dot com"),
+            Some("john_smith.jr@gmail.com".to_string())
+        );
+        // A leading "dot" is a word, not a separator
+        assert_eq!(
+            parse("dot john at gmail dot com"),
+            Some("dot john@gmail.com".to_string())
+        );
     }
 
     #[test]
@@ -262,6 +395,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_email_letter_o() {
+        assert_eq!(
+            parse("j o h n at gmail dot com"),
+            Some("john@gmail.com".to_string())
+        );
+        assert_eq!(
+            parse("j oh at gmail dot com"),
+            Some("jo@gmail.com".to_string())
+        );
+        assert_eq!(
+            parse("zero zero seven at gmail dot com"),
+            Some("007@gmail.com".to_string())
+        );
+        assert_eq!(
+            parse("agent oh oh seven at gmail dot com"),
+            Some("agent007@gmail.com".to_string())
+        );
+    }
+
     #[test]
     fn test_url_with_protocol() {
         assert_eq!(
@@ -281,5 +434,39 @@ mod tests {
     #[test]
     fn test_simple_domain() {
         assert_eq!(parse("nvidia dot com"), Some("nvidia.com".to_string()));
+        assert_eq!(parse("n vidia dot com"), Some("nvidia.com".to_string()));
+    }
+
+    #[test]
+    fn test_multi_level_domain() {
+        assert_eq!(
+            parse("mail dot google dot co dot uk"),
+            Some("mail.google.co.uk".to_string())
+        );
+        assert_eq!(
+            parse("api dot example dot com"),
+            Some("api.example.com".to_string())
+        );
+        assert_eq!(
+            parse("www dot bbc dot co dot uk"),
+            Some("www.bbc.co.uk".to_string())
+        );
+    }
+
+    #[test]
+    fn test_initials() {
+        assert_eq!(parse("u dot s dot a"), Some("U.S.A.".to_string()));
+        assert_eq!(parse("p dot o dot box"), Some("P.O. box".to_string()));
+        assert_eq!(parse("P dot O dot Box"), Some("P.O. Box".to_string()));
+        assert_eq!(parse("a dot b dot com"), Some("a.b.com".to_string()));
+        assert_eq!(parse("u dot s"), Some("U.S.".to_string()));
+    }
+
+    #[test]
+    fn test_domain_rejects_prose() {
+        assert_eq!(parse("built with dot net framework"), None);
+        assert_eq!(parse("i use nvidia dot com"), None);
+        assert_eq!(parse("with dot net"), None);
+        assert_eq!(parse("u dot s dot a today"), None);
     }
 }
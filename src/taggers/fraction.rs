@@ -0,0 +1,305 @@
+//! Fraction tagger.
+//!
+//! Converts spoken fractions to written form:
+//! - "three slash four" → "3/4"
+//! - "three over four" → "3/4"
+//! - "one out of four" → "1/4"
+//! - "one twenty over eighty" → "120/80" (blood pressure)
+//! - "three quarters" → "3/4", "two and a half" → "2 1/2"
+
+use super::cardinal::words_to_number;
+use super::decimal::DENOMINATORS;
+use crate::NormalizeOptions;
+
+/// Parse spoken fraction expression to written form.
+pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Parse spoken fraction expression, applying output options.
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
+    let original = input.trim();
+    let input_lower = original.to_lowercase();
+
+    if let Some(result) = parse_blood_pressure(original, &input_lower) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_fraction_bar(&input_lower) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_out_of(&input_lower, options) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_word_fraction(&input_lower) {
+        return Some(result);
+    }
+
+    None
+}
+
+/// Parse a fraction with a spoken denominator: "three quarters" → "3/4",
+/// "one half" → "1/2", "two and a half" → "2 1/2".
+///
+/// A numerator is required, so bare "half" and "quarter" stay as words, and
+/// "a half" only counts after a whole number ("an hour and a half" keeps its
+/// article).
+fn parse_word_fraction(input: &str) -> Option<String> {
+    if let Some((whole, fraction)) = input.split_once(" and ") {
+        let whole = words_to_number(whole)?;
+        let fraction = match fraction.split_once(' ') {
+            Some(("a" | "an", denominator)) => format!("one {}", denominator),
+            _ => fraction.to_string(),
+        };
+        let (numerator, denominator) = parse_simple_fraction(&fraction)?;
+        if numerator >= denominator {
+            return None;
+        }
+        return Some(format!("{} {}/{}", whole, numerator, denominator));
+    }
+
+    let (numerator, denominator) = parse_simple_fraction(input)?;
+    Some(format!("{}/{}", numerator, denominator))
+}
+
+/// Parse "<numerator> <denominator>" into its parts. The denominator must
+/// agree with the numerator: "one half", "three quarters", not "two half".
+fn parse_simple_fraction(input: &str) -> Option<(i64, i64)> {
+    let (numerator, denominator) = input.rsplit_once(' ')?;
+    let numerator = i64::try_from(words_to_number(numerator)?).ok()?;
+    let &(_, _, value) = DENOMINATORS.iter().find(|(singular, plural, _)| {
+        if numerator == 1 {
+            denominator == *singular
+        } else {
+            denominator == *plural
+        }
+    })?;
+    // "one hundredth" and "one thousandth" read as ordinals ("100th")
+    if numerator == 0 || (numerator == 1 && value >= 100) {
+        return None;
+    }
+    Some((numerator, value))
+}
+
+/// Parse "X slash Y" / "X over Y" with numeric operands on both sides.
+///
+/// Non-numeric operands ("example dot com slash path") are left to the
+/// electronic tagger.
+fn parse_fraction_bar(input: &str) -> Option<String> {
+    let (numerator, denominator) = input
+        .split_once(" slash ")
+        .or_else(|| input.split_once(" over "))?;
+
+    let numerator = words_to_number(numerator)?;
+    let denominator = words_to_number(denominator)?;
+    if denominator == 0 {
+        return None;
+    }
+
+    Some(format!("{}/{}", numerator, denominator))
+}
+
+/// Parse a blood pressure reading "<systolic> over <diastolic>".
+///
+/// After a "blood pressure" cue any spoken systolic form is accepted. Without
+/// it, only the clinical reading "one twenty" (1 + 20 → 120) is, so plain
+/// fractions like "three over four" are unaffected. The cue is kept as
+/// spoken: "Blood pressure is one twenty over eighty" → "Blood pressure is
+/// 120/80".
+fn parse_blood_pressure(original: &str, input: &str) -> Option<String> {
+    let (cue, reading) = match input.strip_prefix("blood pressure ") {
+        Some(rest) => (true, rest.strip_prefix("is ").unwrap_or(rest)),
+        None => (false, input),
+    };
+    let (systolic, diastolic) = reading.split_once(" over ")?;
+
+    let systolic = match parse_hundreds_reading(systolic) {
+        Some(value) => value,
+        None if cue => words_to_number(systolic)?,
+        None => return None,
+    };
+    let diastolic = words_to_number(diastolic)?;
+
+    if !(70..=250).contains(&systolic) || !(30..=150).contains(&diastolic) {
+        return None;
+    }
+
+    let cue_text = original.get(..input.len() - reading.len())?;
+    Some(format!("{}{}/{}", cue_text, systolic, diastolic))
+}
+
+/// Parse a hundreds digit followed by a two-digit reading:
+/// "one twenty" → 120, "two oh five" → 205
+fn parse_hundreds_reading(input: &str) -> Option<i128> {
+    let (hundreds, rest) = input.split_once(' ')?;
+    let hundreds = match hundreds {
+        "one" => 1,
+        "two" => 2,
+        _ => return None,
+    };
+
+    let rest_value = match rest.strip_prefix("oh ") {
+        Some(digit) => match words_to_number(digit)? {
+            d @ 0..=9 => d,
+            _ => return None,
+        },
+        None => match words_to_number(rest)? {
+            d @ 10..=99 => d,
+            _ => return None,
+        },
+    };
+    Some(hundreds * 100 + rest_value)
+}
+
+/// Parse "X out of Y" with numeric operands on both sides.
+///
+/// Renders "1/4" by default, or "1 out of 4" with `out_of_ratio`. Out of a
+/// hundred becomes a percentage with `out_of_hundred_percent`. Prose like
+/// "one out of the box" has a non-numeric side and is left alone.
+fn parse_out_of(input: &str, options: &NormalizeOptions) -> Option<String> {
+    let (numerator, denominator) = input.split_once(" out of ")?;
+
+    let numerator = words_to_number(numerator)?;
+    let denominator = words_to_number(denominator)?;
+    if denominator == 0 {
+        return None;
+    }
+
+    if options.out_of_hundred_percent && denominator == 100 {
+        return Some(format!("{}{}", numerator, options.percent_sign()));
+    }
+
+    if options.out_of_ratio {
+        Some(format!("{} out of {}", numerator, denominator))
+    } else {
+        Some(format!("{}/{}", numerator, denominator))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slash() {
+        assert_eq!(parse("three slash four"), Some("3/4".to_string()));
+        assert_eq!(parse("one slash sixteen"), Some("1/16".to_string()));
+    }
+
+    #[test]
+    fn test_over() {
+        assert_eq!(parse("three over four"), Some("3/4".to_string()));
+        assert_eq!(
+            parse("twenty one over one hundred"),
+            Some("21/100".to_string())
+        );
+    }
+
+    #[test]
+    fn test_blood_pressure() {
+        assert_eq!(parse("one twenty over eighty"), Some("120/80".to_string()));
+        assert_eq!(
+            parse("one forty five over ninety"),
+            Some("145/90".to_string())
+        );
+        assert_eq!(
+            parse("blood pressure is one hundred ten over seventy"),
+            Some("blood pressure is 110/70".to_string())
+        );
+        assert_eq!(
+            parse("Blood pressure one twenty over eighty"),
+            Some("Blood pressure 120/80".to_string())
+        );
+        // Plain fractions keep their reading
+        assert_eq!(parse("three over four"), Some("3/4".to_string()));
+    }
+
+    #[test]
+    fn test_out_of() {
+        assert_eq!(parse("one out of four"), Some("1/4".to_string()));
+        assert_eq!(parse("nine out of ten"), Some("9/10".to_string()));
+        assert_eq!(parse("one out of the box"), None);
+        assert_eq!(parse("one out of zero"), None);
+
+        let options = NormalizeOptions {
+            out_of_ratio: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("one out of four", &options),
+            Some("1 out of 4".to_string())
+        );
+    }
+
+    #[test]
+    fn test_out_of_hundred() {
+        assert_eq!(
+            parse("ninety out of one hundred"),
+            Some("90/100".to_string())
+        );
+
+        let options = NormalizeOptions {
+            out_of_hundred_percent: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("ninety out of one hundred", &options),
+            Some("90 %".to_string())
+        );
+        assert_eq!(
+            parse_with_options("ninety out of a hundred", &options),
+            Some("90 %".to_string())
+        );
+        assert_eq!(
+            parse_with_options("nine out of ten", &options),
+            Some("9/10".to_string())
+        );
+
+        let options = NormalizeOptions {
+            out_of_hundred_percent: true,
+            percent_space: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("ninety out of one hundred", &options),
+            Some("90%".to_string())
+        );
+    }
+
+    #[test]
+    fn test_word_fraction() {
+        assert_eq!(parse("one half"), Some("1/2".to_string()));
+        assert_eq!(parse("three quarters"), Some("3/4".to_string()));
+        assert_eq!(parse("seven eighths"), Some("7/8".to_string()));
+        assert_eq!(parse("one sixteenth"), Some("1/16".to_string()));
+        assert_eq!(parse("two thirds"), Some("2/3".to_string()));
+        assert_eq!(parse("two and a half"), Some("2 1/2".to_string()));
+        assert_eq!(parse("one and three quarters"), Some("1 3/4".to_string()));
+        assert_eq!(parse("three hundredths"), Some("3/100".to_string()));
+    }
+
+    #[test]
+    fn test_word_fraction_rejects() {
+        assert_eq!(parse("half"), None);
+        assert_eq!(parse("quarter"), None);
+        assert_eq!(parse("a half"), None);
+        assert_eq!(parse("two half"), None);
+        assert_eq!(parse("one quarters"), None);
+        assert_eq!(parse("two and five quarters"), None);
+        assert_eq!(parse("twenty third"), None);
+        // Left to the ordinal tagger
+        assert_eq!(parse("one hundredth"), None);
+        // Numerators beyond i64 are rejected rather than wrapped
+        assert_eq!(parse("ten quintillion halves"), None);
+    }
+
+    #[test]
+    fn test_no_match() {
+        assert_eq!(parse("example dot com slash path"), None);
+        assert_eq!(parse("three slash"), None);
+        assert_eq!(parse("game over"), None);
+        assert_eq!(parse("one slash zero"), None);
+    }
+}
@@ -5,15 +5,82 @@
 //! - "eighteen point five kilometers" → "18.5 km"
 //! - "two hundred kilometers per hour" → "200 km/h"
 //! - "thirty one thousand square feet" → "31000 sq ft"
+//! - "forty point seven one two eight degrees north" → "40.7128°N"
+//! - "eleven stone four" → "11 st 4 lb" (British units option)
 
-use super::cardinal::words_to_number;
+use super::cardinal::{split_sign, words_to_number};
 use super::decimal;
+use crate::{NormalizeOptions, TemperatureScale};
 
 /// Parse spoken measurement expression to written form.
 pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Parse spoken measurement expression, applying output options.
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
+    let result = parse_measure(input, options)?;
+    match result.strip_suffix(" %") {
+        Some(value) => Some(format_percent(value, options)),
+        None => Some(result),
+    }
+}
+
+/// Format a percentage value, giving sub-1 values a leading zero
+/// (".5" → "0.5") and spacing "%" per `percent_space`.
+fn format_percent(value: &str, options: &NormalizeOptions) -> String {
+    let (sign, digits) = value.split_at(value.find(|c: char| c != '+' && c != '-').unwrap_or(0));
+    let zero = if digits.starts_with('.') { "0" } else { "" };
+    format!("{}{}{}{}", sign, zero, digits, options.percent_sign())
+}
+
+/// Parse spoken measurement expression with "%" always spaced.
+fn parse_measure(input: &str, options: &NormalizeOptions) -> Option<String> {
+    // Lowercasing is safe: output is only digits and unit symbols, and in
+    // sentence mode neighbouring words are separate spans that keep their case
     let input = input.to_lowercase();
+    let input = decimal::decimal_word_as_point(&input);
     let input = input.trim();
 
+    // Large scale words never come before "percent" ("one point five
+    // million percent"); leave such input as spoken
+    if input.ends_with(" percent")
+        && input
+            .split_whitespace()
+            .any(|w| matches!(w, "million" | "billion" | "trillion"))
+    {
+        return None;
+    }
+
+    // Signed percentage change ("up five percent" → "+5 %")
+    if options.signed_percent {
+        if let Some(result) = parse_signed_percent(input) {
+            return Some(result);
+        }
+    }
+
+    // British body weight ("eleven stone four" → "11 st 4 lb")
+    if options.british_units {
+        if let Some(result) = parse_stone(input) {
+            return Some(result);
+        }
+    }
+
+    // Try decimal-degree coordinates ("forty point seven degrees north")
+    if let Some(result) = parse_coordinate(input) {
+        return Some(result);
+    }
+
+    // Try fractional percentages ("half of one percent")
+    if let Some(result) = parse_fractional_percent(input) {
+        return Some(result);
+    }
+
+    // Try a fraction spoken after the unit ("two hours and a half")
+    if let Some(result) = parse_fraction_after_unit(input) {
+        return Some(result);
+    }
+
     // Try compound units first (most specific)
     if let Some(result) = parse_compound_unit(input) {
         return Some(result);
@@ -24,22 +91,45 @@ pub fn parse(input: &str) -> Option<String> {
         return Some(result);
     }
 
+    // Bare degrees take the configured default scale
+    if let Some(scale) = options.default_temperature_scale {
+        if let Some(result) = parse_bare_degrees(input, scale) {
+            return Some(result);
+        }
+    }
+
     None
 }
 
+/// Parse a temperature without a scale word: "thirty seven point five
+/// degrees" → "37.5 °C" with a Celsius default
+fn parse_bare_degrees(input: &str, scale: TemperatureScale) -> Option<String> {
+    let num_part = input
+        .strip_suffix(" degrees")
+        .or_else(|| input.strip_suffix(" degree"))?;
+    let (sign, num_part) = split_sign(num_part);
+    let value = parse_number_value(num_part)?;
+
+    Some(match scale {
+        TemperatureScale::Unspecified => format!("{}{}°", sign, value),
+        TemperatureScale::Celsius => format!("{}{} °C", sign, value),
+        TemperatureScale::Fahrenheit => format!("{}{} °F", sign, value),
+    })
+}
+
 /// Parse compound units like "kilometers per hour" → "km/h"
 fn parse_compound_unit(input: &str) -> Option<String> {
     // Special case: "X miles per hour" → "X mph"
     if input.ends_with(" miles per hour") {
         let num_part = input.strip_suffix(" miles per hour")?;
-        let num_value = parse_number_value(num_part.trim())?;
+        let num_value = parse_signed_number_value(num_part.trim())?;
         return Some(format!("{} mph", num_value));
     }
 
     // Special case: "X kilograms force per square centimeter" → "X kgf/cm²"
     if input.ends_with(" kilograms force per square centimeter") {
         let num_part = input.strip_suffix(" kilograms force per square centimeter")?;
-        let num_value = parse_number_value(num_part.trim())?;
+        let num_value = parse_signed_number_value(num_part.trim())?;
         return Some(format!("{} kgf/cm²", num_value));
     }
 
@@ -49,7 +139,7 @@ fn parse_compound_unit(input: &str) -> Option<String> {
         let denom_part = &input[idx + 12..]; // " per square " is 12 chars
 
         // Parse numerator (just number, no unit)
-        let num_value = parse_number_value(num_part.trim())?;
+        let num_value = parse_signed_number_value(num_part.trim())?;
         let denom_unit = get_unit_symbol(denom_part)?;
 
         return Some(format!("{} /{}²", num_value, denom_unit));
@@ -60,7 +150,7 @@ fn parse_compound_unit(input: &str) -> Option<String> {
         let num_part = &input[..idx];
         let denom_part = &input[idx + 11..];
 
-        let num_value = parse_number_value(num_part.trim())?;
+        let num_value = parse_signed_number_value(num_part.trim())?;
         let denom_unit = get_unit_symbol(denom_part)?;
 
         return Some(format!("{} /{}³", num_value, denom_unit));
@@ -76,11 +166,146 @@ fn parse_compound_unit(input: &str) -> Option<String> {
             let denom_unit = get_unit_symbol(denom_part)?;
             return Some(format!("{} {}/{}", num_value, num_unit, denom_unit));
         }
+
+        // Angular rates: "minus five degrees per hour" → "-5 °/h".
+        // Bare "degrees" is only a unit in a rate; on its own it stays a word.
+        let degrees_part = num_unit_part
+            .strip_suffix(" degrees")
+            .or_else(|| num_unit_part.strip_suffix(" degree"));
+        if let Some(num_part) = degrees_part {
+            let num_value = parse_signed_number_value(num_part.trim())?;
+            let denom_unit = get_unit_symbol(denom_part)?;
+            return Some(format!("{} °/{}", num_value, denom_unit));
+        }
     }
 
     None
 }
 
+/// Parse stone weights with optional trailing pounds:
+/// "eleven stone" → "11 st", "eleven stone four" → "11 st 4 lb"
+fn parse_stone(input: &str) -> Option<String> {
+    let (stone_part, pounds_part) = input
+        .split_once(" stones")
+        .or_else(|| input.split_once(" stone"))?;
+    let stone = parse_number_value(stone_part.trim())?;
+
+    let pounds_part = pounds_part.trim();
+    if pounds_part.is_empty() {
+        return Some(format!("{} st", stone));
+    }
+
+    // A stone is fourteen pounds, so the remainder is 0-13
+    let pounds_part = pounds_part
+        .strip_suffix(" pounds")
+        .or_else(|| pounds_part.strip_suffix(" pound"))
+        .unwrap_or(pounds_part);
+    let pounds = words_to_number(pounds_part)?;
+    if pounds > 13 {
+        return None;
+    }
+    Some(format!("{} st {} lb", stone, pounds))
+}
+
+/// Parse decimal-degree coordinates with a compass direction:
+/// "forty point seven one two eight degrees north" → "40.7128°N"
+fn parse_coordinate(input: &str) -> Option<String> {
+    let (rest, direction) = input.rsplit_once(' ')?;
+    let (direction, max) = match direction {
+        "north" => ("N", 90.0),
+        "south" => ("S", 90.0),
+        "east" => ("E", 180.0),
+        "west" => ("W", 180.0),
+        _ => return None,
+    };
+    let num_part = rest
+        .strip_suffix(" degrees")
+        .or_else(|| rest.strip_suffix(" degree"))?;
+
+    // The direction carries the sign, so the value itself is unsigned
+    let value = parse_number_value(num_part.trim())?;
+    if value.parse::<f64>().ok()? > max {
+        return None;
+    }
+    Some(format!("{}°{}", value, direction))
+}
+
+/// Parse percentage changes: "up five percent" → "+5 %", "down three percent" → "-3 %"
+fn parse_signed_percent(input: &str) -> Option<String> {
+    if !input.ends_with(" percent") {
+        return None;
+    }
+
+    let (sign, rest) = if let Some(rest) = input.strip_prefix("up ") {
+        ("+", rest)
+    } else if let Some(rest) = input.strip_prefix("down ") {
+        ("-", rest)
+    } else {
+        return None;
+    };
+
+    let value = parse(rest)?;
+    if value.starts_with('-') {
+        return None;
+    }
+    Some(format!("{}{}", sign, value))
+}
+
+/// Parse fractional percentages:
+/// - "half of one percent" → "0.5 %"
+/// - "a tenth of a percent" → "0.1 %"
+/// - "three quarters of a percent" → "0.75 %"
+fn parse_fractional_percent(input: &str) -> Option<String> {
+    let rest = input.strip_suffix(" percent")?;
+
+    let (fraction_part, base_part) = if let Some((fraction, base)) = rest.split_once(" of ") {
+        (fraction, base)
+    } else {
+        // "half a percent"
+        (rest.strip_suffix(" a")?, "a")
+    };
+
+    let (numerator, denominator) = decimal::parse_fraction_words(fraction_part)?;
+    let base = match base_part {
+        "a" | "one" => 1,
+        _ => i64::try_from(words_to_number(base_part)?).ok()?,
+    };
+
+    let value = format_ratio(numerator.checked_mul(base)?, denominator)?;
+    Some(format!("{} %", value))
+}
+
+/// Format numerator/denominator as a terminating decimal: (1, 2) → "0.5".
+/// Returns None for repeating decimals like 1/3.
+fn format_ratio(numerator: i64, denominator: i64) -> Option<String> {
+    let integer = numerator / denominator;
+    let mut remainder = numerator % denominator;
+    if remainder == 0 {
+        return Some(integer.to_string());
+    }
+
+    let mut digits = String::new();
+    while remainder != 0 && digits.len() < 6 {
+        remainder *= 10;
+        digits.push_str(&(remainder / denominator).to_string());
+        remainder %= denominator;
+    }
+
+    if remainder != 0 {
+        return None;
+    }
+    Some(format!("{}.{}", integer, digits))
+}
+
+/// Parse a fraction spoken after the unit: "two hours and a half" →
+/// "2.5 h", "one hour and a quarter" → "1.25 h"
+fn parse_fraction_after_unit(input: &str) -> Option<String> {
+    let (measure, fraction) = input.rsplit_once(" and ")?;
+    let (num_part, unit) = extract_unit(measure)?;
+    let value = decimal::parse_mixed_number(&format!("{} and {}", num_part.trim(), fraction))?;
+    Some(format!("{} {}", value, unit))
+}
+
 /// Parse simple measurement: number + unit
 fn parse_simple_unit(input: &str) -> Option<String> {
     let (value, unit) = parse_number_and_unit(input)?;
@@ -89,12 +314,7 @@ fn parse_simple_unit(input: &str) -> Option<String> {
 
 /// Parse number and unit from input, returning (formatted_number, unit_symbol)
 fn parse_number_and_unit(input: &str) -> Option<(String, String)> {
-    // Handle negative
-    let (is_negative, rest) = if input.starts_with("minus ") {
-        (true, input.strip_prefix("minus ")?)
-    } else {
-        (false, input)
-    };
+    let (sign, rest) = split_sign(input);
 
     // Try to find unit at the end
     let (num_part, unit_symbol) = extract_unit(rest)?;
@@ -102,7 +322,6 @@ fn parse_number_and_unit(input: &str) -> Option<(String, String)> {
     // Parse the number part
     let num_value = parse_number_value(num_part.trim())?;
 
-    let sign = if is_negative { "-" } else { "" };
     Some((format!("{}{}", sign, num_value), unit_symbol))
 }
 
@@ -293,6 +512,8 @@ fn get_unit_mappings() -> Vec<(&'static str, &'static str)> {
         // Time
         (" hours", "h"),
         (" hour", "h"),
+        (" minutes", "min"),
+        (" minute", "min"),
         // Light
         (" lumens", "lm"),
         (" lumen", "lm"),
@@ -301,6 +522,12 @@ fn get_unit_mappings() -> Vec<(&'static str, &'static str)> {
     ]
 }
 
+/// Parse number value with an optional leading sign
+fn parse_signed_number_value(input: &str) -> Option<String> {
+    let (sign, rest) = split_sign(input);
+    Some(format!("{}{}", sign, parse_number_value(rest.trim())?))
+}
+
 /// Parse number value (cardinal, decimal, or with "point")
 fn parse_number_value(input: &str) -> Option<String> {
     // Try decimal first (handles "point" patterns)
@@ -313,7 +540,8 @@ fn parse_number_value(input: &str) -> Option<String> {
         return Some((num as i64).to_string());
     }
 
-    None
+    // Try whole number with a fraction ("five and a half" → "5.5")
+    decimal::parse_mixed_number(input)
 }
 
 #[cfg(test)]
@@ -341,6 +569,11 @@ mod tests {
             parse("minus sixty six kilograms"),
             Some("-66 kg".to_string())
         );
+        assert_eq!(
+            parse("minus five point five kilometers"),
+            Some("-5.5 km".to_string())
+        );
+        assert_eq!(parse("negative five kilometers"), Some("-5 km".to_string()));
     }
 
     #[test]
@@ -360,6 +593,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_negative_compound_units() {
+        assert_eq!(
+            parse("minus five degrees per hour"),
+            Some("-5 °/h".to_string())
+        );
+        assert_eq!(
+            parse("minus two hundred kilometers per hour"),
+            Some("-200 km/h".to_string())
+        );
+        assert_eq!(
+            parse("minus fifty six per square kilometer"),
+            Some("-56 /km²".to_string())
+        );
+        assert_eq!(
+            parse("minus five miles per hour"),
+            Some("-5 mph".to_string())
+        );
+        // Bare degrees without a rate is not a unit
+        assert_eq!(parse("minus twenty degrees"), None);
+        assert_eq!(parse("minus five minutes"), Some("-5 min".to_string()));
+    }
+
     #[test]
     fn test_special_units() {
         assert_eq!(parse("two kilo watt hours"), Some("2 kWh".to_string()));
@@ -373,4 +629,276 @@ mod tests {
             Some("18.14 %".to_string())
         );
     }
+
+    #[test]
+    fn test_trailing_zeros() {
+        assert_eq!(
+            parse("three point one zero kilometers"),
+            Some("3.10 km".to_string())
+        );
+        assert_eq!(
+            parse("five point zero zero percent"),
+            Some("5.00 %".to_string())
+        );
+    }
+
+    #[test]
+    fn test_age_is_not_a_unit() {
+        assert_eq!(parse("twenty one years old"), None);
+        assert_eq!(parse("twenty one years"), None);
+    }
+
+    #[test]
+    fn test_decimal_word() {
+        assert_eq!(
+            parse("two decimal five kilometers"),
+            Some("2.5 km".to_string())
+        );
+        assert_eq!(
+            parse("ten decimal five percent"),
+            Some("10.5 %".to_string())
+        );
+    }
+
+    #[test]
+    fn test_percent_with_article() {
+        assert_eq!(parse("a hundred percent"), Some("100 %".to_string()));
+        assert_eq!(parse("a thousand percent"), Some("1000 %".to_string()));
+
+        let options = NormalizeOptions {
+            percent_space: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("a hundred percent", &options),
+            Some("100%".to_string())
+        );
+        assert_eq!(
+            parse_with_options("a thousand percent", &options),
+            Some("1000%".to_string())
+        );
+    }
+
+    #[test]
+    fn test_sub_one_percent() {
+        assert_eq!(parse("point five percent"), Some("0.5 %".to_string()));
+        assert_eq!(parse("point o five percent"), Some("0.05 %".to_string()));
+        assert_eq!(
+            parse("minus point five percent"),
+            Some("-0.5 %".to_string())
+        );
+
+        let options = NormalizeOptions {
+            percent_space: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("point five percent", &options),
+            Some("0.5%".to_string())
+        );
+        assert_eq!(
+            parse_with_options("eighteen point one four percent", &options),
+            Some("18.14%".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mixed_zero_and_oh() {
+        assert_eq!(
+            parse("zero point o five kilometers"),
+            Some("0.05 km".to_string())
+        );
+        assert_eq!(
+            parse("zero point zero o five percent"),
+            Some("0.005 %".to_string())
+        );
+    }
+
+    #[test]
+    fn test_stone() {
+        let options = NormalizeOptions {
+            british_units: true,
+            ..Default::default()
+        };
+        let parse_gb = |s: &str| parse_with_options(s, &options);
+        assert_eq!(parse_gb("eleven stone"), Some("11 st".to_string()));
+        assert_eq!(
+            parse_gb("eleven stone four"),
+            Some("11 st 4 lb".to_string())
+        );
+        assert_eq!(
+            parse_gb("twelve stones six pounds"),
+            Some("12 st 6 lb".to_string())
+        );
+        assert_eq!(parse_gb("eleven stone twenty"), None);
+        assert_eq!(parse_gb("stone"), None);
+        // Off by default
+        assert_eq!(parse("eleven stone"), None);
+    }
+
+    #[test]
+    fn test_coordinates() {
+        assert_eq!(
+            parse("forty point seven one two eight degrees north"),
+            Some("40.7128°N".to_string())
+        );
+        assert_eq!(
+            parse("seventy four point zero zero six degrees west"),
+            Some("74.006°W".to_string())
+        );
+        assert_eq!(
+            parse("thirty three point eight six eight degrees south"),
+            Some("33.868°S".to_string())
+        );
+        assert_eq!(parse("ninety five degrees north"), None);
+        assert_eq!(parse("minus five degrees west"), None);
+    }
+
+    #[test]
+    fn test_signed_percent() {
+        let options = NormalizeOptions {
+            signed_percent: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("up five percent", &options),
+            Some("+5 %".to_string())
+        );
+        assert_eq!(
+            parse_with_options("down three percent", &options),
+            Some("-3 %".to_string())
+        );
+        assert_eq!(
+            parse_with_options("up two point five percent", &options),
+            Some("+2.5 %".to_string())
+        );
+        assert_eq!(
+            parse_with_options("five percent", &options),
+            Some("5 %".to_string())
+        );
+        // Off by default
+        assert_eq!(parse("up five percent"), None);
+    }
+
+    #[test]
+    fn test_fractional_percent() {
+        assert_eq!(parse("half of one percent"), Some("0.5 %".to_string()));
+        assert_eq!(parse("a tenth of a percent"), Some("0.1 %".to_string()));
+        assert_eq!(parse("half a percent"), Some("0.5 %".to_string()));
+        assert_eq!(
+            parse("three quarters of a percent"),
+            Some("0.75 %".to_string())
+        );
+        assert_eq!(parse("half of two percent"), Some("1 %".to_string()));
+        // Repeating decimals are not rendered
+        assert_eq!(parse("a third of a percent"), None);
+        // Values too large for the arithmetic are rejected
+        assert_eq!(
+            parse("nine quintillion halves of nine quintillion percent"),
+            None
+        );
+        assert_eq!(parse("half of ten quintillion percent"), None);
+    }
+
+    #[test]
+    fn test_mixed_number_units() {
+        assert_eq!(
+            parse("five and a half kilometers"),
+            Some("5.5 km".to_string())
+        );
+        assert_eq!(
+            parse("two and a quarter miles"),
+            Some("2.25 mi".to_string())
+        );
+        assert_eq!(
+            parse("one and three quarters kilograms"),
+            Some("1.75 kg".to_string())
+        );
+        assert_eq!(
+            parse("minus two and a half degrees celsius"),
+            Some("-2.5 °C".to_string())
+        );
+        assert_eq!(parse("five and a third kilometers"), None);
+    }
+
+    #[test]
+    fn test_percent_rejects_scale() {
+        assert_eq!(parse("one point five percent"), Some("1.5 %".to_string()));
+        assert_eq!(parse("one thousand percent"), Some("1000 %".to_string()));
+        assert_eq!(parse("one point five million percent"), None);
+        assert_eq!(parse("five billion percent"), None);
+    }
+
+    #[test]
+    fn test_fraction_after_unit() {
+        assert_eq!(parse("two hours and a half"), Some("2.5 h".to_string()));
+        assert_eq!(parse("one hour and a quarter"), Some("1.25 h".to_string()));
+        assert_eq!(
+            parse("three kilometers and three quarters"),
+            Some("3.75 km".to_string())
+        );
+        assert_eq!(parse("two hours and a third"), None);
+        assert_eq!(parse("two hours and ten minutes"), None);
+    }
+
+    #[test]
+    fn test_decimal_temperatures() {
+        assert_eq!(
+            parse("thirty seven point five degrees celsius"),
+            Some("37.5 °C".to_string())
+        );
+        assert_eq!(
+            parse("ninety eight point six degrees fahrenheit"),
+            Some("98.6 °F".to_string())
+        );
+        assert_eq!(parse("thirty seven point five degrees"), None);
+
+        let celsius = NormalizeOptions {
+            default_temperature_scale: Some(TemperatureScale::Celsius),
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("thirty seven point five degrees", &celsius),
+            Some("37.5 °C".to_string())
+        );
+        assert_eq!(
+            parse_with_options("minus two degrees", &celsius),
+            Some("-2 °C".to_string())
+        );
+        // An explicit scale wins over the default
+        assert_eq!(
+            parse_with_options("ninety eight point six degrees fahrenheit", &celsius),
+            Some("98.6 °F".to_string())
+        );
+
+        let unspecified = NormalizeOptions {
+            default_temperature_scale: Some(TemperatureScale::Unspecified),
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("thirty seven point five degrees", &unspecified),
+            Some("37.5°".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mixed_number_percent() {
+        assert_eq!(
+            parse("twelve and a half percent"),
+            Some("12.5 %".to_string())
+        );
+        assert_eq!(
+            parse("ninety nine and a half percent"),
+            Some("99.5 %".to_string())
+        );
+
+        let options = NormalizeOptions {
+            percent_space: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("twelve and a half percent", &options),
+            Some("12.5%".to_string())
+        );
+    }
 }
@@ -12,20 +12,34 @@
 //! - electronic: URLs and emails
 //! - fraction: fractional numbers
 //! - punctuation: spoken punctuation
+//! - reference: document numbers after a cue word
+//! - range: "between X and Y" / "X to Y" ranges
 //! - whitelist: pass-through words
+//!
+//! cardinal, ordinal, decimal, punctuation, whitelist and word are always
+//! built; every other tagger sits behind a Cargo feature of the same name.
 
 pub mod cardinal;
+#[cfg(feature = "date")]
 pub mod date;
 pub mod decimal;
+#[cfg(feature = "electronic")]
 pub mod electronic;
+#[cfg(feature = "fraction")]
+pub mod fraction;
+#[cfg(feature = "measure")]
 pub mod measure;
+#[cfg(feature = "money")]
 pub mod money;
 pub mod ordinal;
 pub mod punctuation;
+#[cfg(feature = "range")]
+pub mod range;
+#[cfg(feature = "reference")]
+pub mod reference;
+#[cfg(feature = "telephone")]
 pub mod telephone;
+#[cfg(feature = "time")]
 pub mod time;
 pub mod whitelist;
 pub mod word;
-
-// TODO: Add remaining taggers
-// pub mod fraction;
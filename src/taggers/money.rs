@@ -5,11 +5,69 @@
 //! - "five dollars and fifty cents" → "$5.50"
 //! - "one cent" → "$0.01"
 //! - "fifteen hundred dollars" → "$1500"
+//! - "five pounds and fifty pence" → "£5.50"
+//! - "two point five billion euros" → "€2.5 billion"
+//! - "five dollars" → "$ 5" (symbol space option)
+//! - "half a dollar" → "$0.50"
 
-use super::cardinal::words_to_number;
+use super::cardinal::{split_sign, words_to_number};
+use super::decimal::{self, decimal_word_as_point};
+use crate::NormalizeOptions;
+
+/// Currency symbols that lead written amounts
+const SYMBOLS: [char; 5] = ['$', '£', '€', '₩', '¥'];
 
 /// Parse spoken money expression to written form.
 pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Parse spoken money expression, applying output options.
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
+    // The sign goes before the symbol: "minus five dollars" → "-$5".
+    // Slice the original so scale words keep their casing.
+    let trimmed = input.trim();
+    let lower = trimmed.to_lowercase();
+    let (sign, lower_rest) = split_sign(&lower);
+    let rest = decimal_word_as_point(&trimmed[lower.len() - lower_rest.len()..]);
+    let rest = rest.trim();
+
+    let shorthand = if options.shorthand_cents {
+        parse_shorthand_cents(rest)
+    } else {
+        None
+    };
+    let result = shorthand.or_else(|| parse_amount(rest))?;
+    if result.starts_with('-') {
+        return None;
+    }
+    if !options.symbol_space {
+        return Some(format!("{}{}", sign, result));
+    }
+
+    match result.chars().next() {
+        Some(symbol) if SYMBOLS.contains(&symbol) => Some(format!(
+            "{}{} {}",
+            sign,
+            symbol,
+            &result[symbol.len_utf8()..]
+        )),
+        _ => Some(format!("{}{}", sign, result)),
+    }
+}
+
+/// Whether the input mixes a currency word with "point" cents: "five
+/// dollars point fifty". The phrase is malformed, so it is not parsed, and
+/// is best kept as spoken rather than read as "$5" and ".50".
+pub fn is_point_after_currency(input: &str) -> bool {
+    let input = input.trim().to_lowercase();
+    input.split_once(" point ").is_some_and(|(amount, digits)| {
+        parse(amount).is_some() && decimal::parse(&format!("point {}", digits)).is_some()
+    })
+}
+
+/// Parse spoken money expression with the symbol attached to the amount.
+fn parse_amount(input: &str) -> Option<String> {
     let original = input.trim();
     let input_lower = original.to_lowercase();
 
@@ -38,6 +96,11 @@ pub fn parse(input: &str) -> Option<String> {
         return Some(result);
     }
 
+    // Try pounds and pence
+    if let Some(result) = parse_pounds_and_pence(&input_lower) {
+        return Some(result);
+    }
+
     if let Some(result) = parse_dollars(&input_lower) {
         return Some(result);
     }
@@ -46,6 +109,10 @@ pub fn parse(input: &str) -> Option<String> {
         return Some(result);
     }
 
+    if let Some(result) = parse_fraction_of_dollar(&input_lower) {
+        return Some(result);
+    }
+
     None
 }
 
@@ -123,53 +190,70 @@ fn parse_decimal_dollars(input: &str) -> Option<String> {
     None
 }
 
+/// A currency with a major and minor unit (dollars and cents, pounds and pence).
+struct Currency {
+    symbol: &'static str,
+    /// Spoken major unit names, longest first
+    major: &'static [&'static str],
+    /// Spoken minor unit names, longest first
+    minor: &'static [&'static str],
+}
+
+const USD: Currency = Currency {
+    symbol: "$",
+    major: &["united states dollars", "dollars", "dollar"],
+    minor: &["cents", "cent"],
+};
+
+const GBP: Currency = Currency {
+    symbol: "£",
+    major: &["pounds", "pound"],
+    minor: &["pence", "penny"],
+};
+
 /// Parse "X dollars and Y cents" pattern
 fn parse_dollars_and_cents(input: &str) -> Option<String> {
-    // Pattern: "X united states dollars and Y cents"
-    if let Some((dollars_part, rest)) = input.split_once(" united states dollars and ") {
-        if rest.ends_with(" cents") || rest.ends_with(" cent") {
-            let cents_words = rest.trim_end_matches(" cents").trim_end_matches(" cent");
-            let dollars = words_to_number(dollars_part)? as i64;
-            let cents = words_to_number(cents_words)? as i64;
-            return Some(format!("${}.{:02}", dollars, cents));
-        }
-    }
+    parse_major_and_minor(input, &USD)
+}
 
-    // Pattern: "X dollar and Y cents" (singular)
-    if let Some((dollars_part, rest)) = input.split_once(" dollar and ") {
-        if rest.ends_with(" cents") || rest.ends_with(" cent") {
-            let cents_words = rest.trim_end_matches(" cents").trim_end_matches(" cent");
-            let dollars = words_to_number(dollars_part)? as i64;
-            let cents = words_to_number(cents_words)? as i64;
-            return Some(format!("${}.{:02}", dollars, cents));
-        }
-    }
+/// Parse "X pounds and Y pence" pattern
+fn parse_pounds_and_pence(input: &str) -> Option<String> {
+    parse_major_and_minor(input, &GBP)
+}
 
-    // Pattern: "X dollars and Y cents"
-    if let Some((dollars_part, rest)) = input.split_once(" dollars and ") {
-        if rest.ends_with(" cents") || rest.ends_with(" cent") {
-            let cents_words = rest.trim_end_matches(" cents").trim_end_matches(" cent");
-            let dollars = words_to_number(dollars_part)? as i64;
-            let cents = words_to_number(cents_words)? as i64;
-            return Some(format!("${}.{:02}", dollars, cents));
-        }
-    }
+/// Parse a major + minor amount for a currency:
+/// - "X dollars and Y cents" / "X dollars Y cents"
+/// - "X pounds and Y" / "X pounds Y" (implied minor unit, 1-99)
+fn parse_major_and_minor(input: &str, currency: &Currency) -> Option<String> {
+    for major in currency.major {
+        for separator in [" and ", " "] {
+            let pattern = format!(" {}{}", major, separator);
+            let Some((major_part, rest)) = input.split_once(&pattern) else {
+                continue;
+            };
 
-    // Pattern: "X dollars Y cents" (without "and")
-    if let Some((dollars_part, rest)) = input.split_once(" dollars ") {
-        if rest.ends_with(" cents") {
-            let cents_words = rest.trim_end_matches(" cents");
-            let dollars = words_to_number(dollars_part)? as i64;
-            let cents = words_to_number(cents_words)? as i64;
-            return Some(format!("${}.{:02}", dollars, cents));
-        }
-        // Pattern: "X dollars Y" (implied cents, e.g., "seventy five dollars sixty three")
-        if let Some(cents) = words_to_number(rest) {
-            let cents = cents as i64;
-            if cents > 0 && cents < 100 {
-                let dollars = words_to_number(dollars_part)? as i64;
-                return Some(format!("${}.{:02}", dollars, cents));
+            let minor_part = currency
+                .minor
+                .iter()
+                .find_map(|m| rest.strip_suffix(&format!(" {}", m)));
+
+            let whole = words_to_number(major_part)? as i64;
+            let minor = match minor_part {
+                Some(words) => words_to_number(words)? as i64,
+                None => {
+                    // Implied minor unit ("seventy five dollars sixty three")
+                    let minor = words_to_number(rest)? as i64;
+                    if minor == 0 {
+                        return None;
+                    }
+                    minor
+                }
+            };
+            if minor >= 100 {
+                return None;
             }
+
+            return Some(format!("{}{}.{:02}", currency.symbol, whole, minor));
         }
     }
 
@@ -201,6 +285,10 @@ fn parse_dollars(input: &str) -> Option<String> {
 }
 
 /// Parse money number, handling shorthand like "one fifty five" = 155
+///
+/// A single digit followed by a two-digit number is read as hundreds, as in
+/// the NeMo grammar; see `parse_shorthand_cents` for the dollars-and-cents
+/// reading.
 fn parse_money_number(input: &str) -> Option<i64> {
     let words: Vec<&str> = input.split_whitespace().collect();
 
@@ -256,27 +344,59 @@ fn parse_cents(input: &str) -> Option<String> {
     None
 }
 
-/// Parse large currency amounts (billions, millions)
-fn parse_large_currency(original: &str, input_lower: &str) -> Option<String> {
-    // "X billion dollars" → "$X billion"
-    for scale in &["trillion", "billion", "million"] {
-        let pattern = format!(" {} dollars", scale);
-        if input_lower.ends_with(&pattern) {
-            let num_part = &input_lower[..input_lower.len() - pattern.len()];
+/// Parse a fraction of a dollar: "half a dollar" → "$0.50",
+/// "three quarters of a dollar" → "$0.75"
+fn parse_fraction_of_dollar(input: &str) -> Option<String> {
+    let fraction = input.strip_suffix(" a dollar")?;
+    let fraction = fraction.strip_suffix(" of").unwrap_or(fraction);
+    let (numerator, denominator) = decimal::parse_fraction_words(fraction)?;
+    let hundredths = numerator.checked_mul(100)?;
+    if !(1..denominator).contains(&numerator) || hundredths % denominator != 0 {
+        return None;
+    }
+    Some(format!("$0.{:02}", hundredths / denominator))
+}
 
-            // Extract original scale word to preserve casing
-            // "dollars" is 7 chars, scale is scale.len() chars, space is 1 char
-            let scale_start = original.len() - 7 - 1 - scale.len();
-            let scale_end = original.len() - 7 - 1;
-            let orig_scale = &original[scale_start..scale_end];
+/// Parse "<digit> <two-digit number> dollars" as dollars and cents:
+/// "five twenty five dollars" → "$5.25"
+fn parse_shorthand_cents(input: &str) -> Option<String> {
+    let input = input.trim().to_lowercase();
+    let num_part = input.strip_suffix(" dollars")?;
+    let (dollars, cents) = num_part.split_once(' ')?;
 
-            // Handle decimal like "two point five billion dollars"
-            if num_part.contains(" point ") {
-                let result = parse_decimal_scale(num_part, orig_scale)?;
-                return Some(result);
+    let dollars = words_to_number(dollars)?;
+    let cents = words_to_number(cents)?;
+    if !(1..=9).contains(&dollars) || !(10..=99).contains(&cents) {
+        return None;
+    }
+    Some(format!("${}.{}", dollars, cents))
+}
+
+/// Currencies spoken after a scale word: (spoken plural, symbol)
+const SCALE_CURRENCIES: [(&str, &str); 3] = [("dollars", "$"), ("pounds", "£"), ("euros", "€")];
+
+/// Parse large currency amounts (billions, millions)
+fn parse_large_currency(original: &str, input_lower: &str) -> Option<String> {
+    // "X billion dollars" → "$X billion", "X billion pounds" → "£X billion"
+    for (currency, symbol) in &SCALE_CURRENCIES {
+        for scale in &["trillion", "billion", "million"] {
+            let pattern = format!(" {} {}", scale, currency);
+            if input_lower.ends_with(&pattern) {
+                let num_part = &input_lower[..input_lower.len() - pattern.len()];
+
+                // Extract original scale word to preserve casing
+                let scale_end = original.len() - currency.len() - 1;
+                let scale_start = scale_end - scale.len();
+                let orig_scale = &original[scale_start..scale_end];
+
+                // Handle decimal like "two point five billion dollars"
+                if num_part.contains(" point ") {
+                    let result = parse_decimal_scale(num_part, symbol, orig_scale)?;
+                    return Some(result);
+                }
+                let num = words_to_number(num_part)? as i64;
+                return Some(format!("{}{} {}", symbol, num, orig_scale));
             }
-            let num = words_to_number(num_part)? as i64;
-            return Some(format!("${} {}", num, orig_scale));
         }
     }
 
@@ -284,7 +404,7 @@ fn parse_large_currency(original: &str, input_lower: &str) -> Option<String> {
 }
 
 /// Parse decimal scale numbers like "two point five"
-fn parse_decimal_scale(input: &str, scale: &str) -> Option<String> {
+fn parse_decimal_scale(input: &str, symbol: &str, scale: &str) -> Option<String> {
     let parts: Vec<&str> = input.split(" point ").collect();
     if parts.len() != 2 {
         return None;
@@ -293,7 +413,7 @@ fn parse_decimal_scale(input: &str, scale: &str) -> Option<String> {
     let integer = words_to_number(parts[0])? as i64;
     let decimal = parse_decimal_digits(parts[1])?;
 
-    Some(format!("${}.{} {}", integer, decimal, scale))
+    Some(format!("{}{}.{} {}", symbol, integer, decimal, scale))
 }
 
 /// Parse decimal digits ("five" → "5", "five o" → "50")
@@ -365,6 +485,122 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pounds_and_pence() {
+        assert_eq!(
+            parse("five pounds and fifty pence"),
+            Some("£5.50".to_string())
+        );
+        assert_eq!(parse("five pounds and fifty"), Some("£5.50".to_string()));
+        assert_eq!(parse("one pound and five pence"), Some("£1.05".to_string()));
+        assert_eq!(
+            parse("twenty pounds ninety nine"),
+            Some("£20.99".to_string())
+        );
+        // Bare pounds are ambiguous with weight
+        assert_eq!(parse("five pounds"), None);
+    }
+
+    #[test]
+    fn test_trailing_zeros() {
+        assert_eq!(
+            parse("three point one zero dollars"),
+            Some("$3.10".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rejects_point_after_currency() {
+        // Mixes "and fifty cents" with "point fifty"; left as spoken
+        assert_eq!(parse("five dollars point fifty"), None);
+        assert_eq!(parse("twenty dollars point o five"), None);
+        assert!(is_point_after_currency("five dollars point fifty"));
+        assert!(is_point_after_currency("twenty dollars point o five"));
+        assert!(!is_point_after_currency("five point five dollars"));
+        assert!(!is_point_after_currency("five dollars point blank"));
+    }
+
+    #[test]
+    fn test_decimal_word() {
+        assert_eq!(
+            parse("three decimal one four dollars"),
+            Some("$3.14".to_string())
+        );
+    }
+
+    #[test]
+    fn test_negative() {
+        assert_eq!(parse("minus five dollars"), Some("-$5".to_string()));
+        assert_eq!(parse("negative five dollars"), Some("-$5".to_string()));
+        assert_eq!(
+            parse("minus five dollars and fifty cents"),
+            Some("-$5.50".to_string())
+        );
+        let options = NormalizeOptions {
+            symbol_space: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("minus five dollars", &options),
+            Some("-$ 5".to_string())
+        );
+    }
+
+    #[test]
+    fn test_symbol_space() {
+        let options = NormalizeOptions {
+            symbol_space: true,
+            ..Default::default()
+        };
+        let parse_spaced = |s: &str| parse_with_options(s, &options);
+        assert_eq!(parse_spaced("five dollars"), Some("$ 5".to_string()));
+        assert_eq!(
+            parse_spaced("five dollars and fifty cents"),
+            Some("$ 5.50".to_string())
+        );
+        assert_eq!(
+            parse_spaced("five pounds and fifty pence"),
+            Some("£ 5.50".to_string())
+        );
+        assert_eq!(parse_spaced("one cent"), Some("$ 0.01".to_string()));
+        assert_eq!(
+            parse_spaced("two million dollars"),
+            Some("$ 2 million".to_string())
+        );
+        assert_eq!(
+            parse_spaced("five billion won"),
+            Some("₩ 5 billion".to_string())
+        );
+        assert_eq!(parse("five dollars"), Some("$5".to_string()));
+    }
+
+    #[test]
+    fn test_mixed_zero_and_oh() {
+        assert_eq!(
+            parse("zero point o five dollars"),
+            Some("$0.05".to_string())
+        );
+        assert_eq!(
+            parse("zero point zero o five dollars"),
+            Some("$0.005".to_string())
+        );
+    }
+
+    #[test]
+    fn test_decimal_dollars_with_oh() {
+        assert_eq!(
+            parse("twenty point o five dollars"),
+            Some("$20.05".to_string())
+        );
+        assert_eq!(
+            parse("twenty point oh five dollars"),
+            Some("$20.05".to_string())
+        );
+        // No integer part is read as written in the NeMo data ("$.506")
+        assert_eq!(parse("point o five dollars"), Some("$.05".to_string()));
+        assert_eq!(parse("point oh five dollars"), Some("$.05".to_string()));
+    }
+
     #[test]
     fn test_cents() {
         assert_eq!(parse("one cent"), Some("$0.01".to_string()));
@@ -372,6 +608,49 @@ mod tests {
         assert_eq!(parse("ninety nine cents"), Some("$0.99".to_string()));
     }
 
+    #[test]
+    fn test_shorthand() {
+        // Hundreds by default, matching the NeMo data
+        assert_eq!(parse("five twenty five dollars"), Some("$525".to_string()));
+        assert_eq!(parse("one fifty five dollars"), Some("$155".to_string()));
+
+        let options = NormalizeOptions {
+            shorthand_cents: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("five twenty five dollars", &options),
+            Some("$5.25".to_string())
+        );
+        assert_eq!(
+            parse_with_options("five dollars", &options),
+            Some("$5".to_string())
+        );
+        assert_eq!(
+            parse_with_options("twenty five dollars", &options),
+            Some("$25".to_string())
+        );
+    }
+
+    #[test]
+    fn test_fraction_of_dollar() {
+        assert_eq!(parse("half a dollar"), Some("$0.50".to_string()));
+        assert_eq!(parse("half of a dollar"), Some("$0.50".to_string()));
+        assert_eq!(parse("quarter of a dollar"), Some("$0.25".to_string()));
+        assert_eq!(parse("a quarter of a dollar"), Some("$0.25".to_string()));
+        assert_eq!(
+            parse("three quarters of a dollar"),
+            Some("$0.75".to_string())
+        );
+        assert_eq!(parse("a tenth of a dollar"), Some("$0.10".to_string()));
+        // Not a whole number of cents, or not less than a dollar
+        assert_eq!(parse("a third of a dollar"), None);
+        assert_eq!(parse("five quarters of a dollar"), None);
+        // Numerators too large for the arithmetic are rejected
+        assert_eq!(parse("ten quintillion quarters of a dollar"), None);
+        assert_eq!(parse("nine quintillion quarters of a dollar"), None);
+    }
+
     #[test]
     fn test_large_amounts() {
         assert_eq!(
@@ -388,6 +667,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_large_amounts_other_currencies() {
+        assert_eq!(
+            parse("two point five billion pounds"),
+            Some("£2.5 billion".to_string())
+        );
+        assert_eq!(
+            parse("two point five billion euros"),
+            Some("€2.5 billion".to_string())
+        );
+        assert_eq!(parse("three million euros"), Some("€3 million".to_string()));
+        assert_eq!(
+            parse("Two point five Billion pounds"),
+            Some("£2.5 Billion".to_string())
+        );
+    }
+
     #[test]
     fn test_not_money() {
         assert_eq!(parse("hello"), None);
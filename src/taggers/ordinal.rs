@@ -4,11 +4,14 @@
 //! - "first" → "1st"
 //! - "twenty first" → "21st"
 //! - "one hundredth" → "100th"
+//! - "first" → "1ˢᵗ" (superscript option)
+//! - "n th" → "nth"
 
 use lazy_static::lazy_static;
 use std::collections::HashMap;
 
 use super::cardinal::words_to_number;
+use crate::NormalizeOptions;
 
 lazy_static! {
     /// Ordinal words mapping to (suffix, value)
@@ -64,6 +67,35 @@ lazy_static! {
 
 /// Parse spoken ordinal to written form.
 pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Parse spoken ordinal, applying output options.
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
+    if let Some(result) = parse_variable_ordinal(input) {
+        return Some(result);
+    }
+
+    let value = parse_ordinal_value(input)?;
+    if options.ordinal_superscript {
+        Some(format_ordinal_superscript(value))
+    } else {
+        Some(format_ordinal(value))
+    }
+}
+
+/// Parse a spelled variable ordinal: "n th" → "nth", "k th" → "kth".
+fn parse_variable_ordinal(input: &str) -> Option<String> {
+    let input = input.trim().to_lowercase();
+    let letter = input.strip_suffix(" th")?;
+    match letter {
+        "n" | "k" | "m" | "i" | "j" | "x" => Some(format!("{}th", letter)),
+        _ => None,
+    }
+}
+
+/// Parse spoken ordinal to its numeric value.
+fn parse_ordinal_value(input: &str) -> Option<i64> {
     let input = input.to_lowercase();
     let words: Vec<&str> = input.split_whitespace().collect();
 
@@ -78,14 +110,21 @@ pub fn parse(input: &str) -> Option<String> {
 
     if words.len() == 1 {
         // Single ordinal word
-        return Some(format_ordinal(ordinal_value));
+        return Some(ordinal_value);
     }
 
     // Multiple words: parse cardinal prefix + ordinal suffix
     // e.g., "twenty first" = 20 + 1 = 21st
     // e.g., "one hundred twenty first" = 100 + 20 + 1 = 121st
 
-    let prefix_words = &words[..words.len() - 1];
+    let mut prefix_words = &words[..words.len() - 1];
+
+    // "one hundred and first": the "and" joins the scale to the ordinal
+    if let [rest @ .., scale, "and"] = prefix_words {
+        if matches!(*scale, "hundred" | "thousand" | "million" | "billion") {
+            prefix_words = &prefix_words[..rest.len() + 1];
+        }
+    }
     let prefix = prefix_words.join(" ");
 
     // Parse the cardinal prefix
@@ -95,11 +134,11 @@ pub fn parse(input: &str) -> Option<String> {
     if let Some(&scale) = ORDINAL_SCALES.get(last_word) {
         // "one hundredth" = 1 * 100 = 100th
         // "twenty five thousandth" = 25 * 1000 = 25000th
-        return Some(format_ordinal(prefix_value * scale));
+        return Some(prefix_value * scale);
     }
 
     // Regular ordinal: add prefix + ordinal value
-    Some(format_ordinal(prefix_value + ordinal_value))
+    Some(prefix_value + ordinal_value)
 }
 
 /// Get the numeric value of an ordinal word.
@@ -118,7 +157,23 @@ fn get_ordinal_value(word: &str) -> Option<i64> {
 
 /// Format a number as an ordinal (1st, 2nd, 3rd, 4th, etc.)
 fn format_ordinal(n: i64) -> String {
-    let suffix = match n % 100 {
+    format!("{}{}", n, ordinal_suffix(n))
+}
+
+/// Format a number as an ordinal with a superscript suffix (1ˢᵗ, 2ⁿᵈ, 3ʳᵈ)
+fn format_ordinal_superscript(n: i64) -> String {
+    let suffix = match ordinal_suffix(n) {
+        "st" => "ˢᵗ",
+        "nd" => "ⁿᵈ",
+        "rd" => "ʳᵈ",
+        _ => "ᵗʰ",
+    };
+    format!("{}{}", n, suffix)
+}
+
+/// Ordinal suffix for a number
+fn ordinal_suffix(n: i64) -> &'static str {
+    match n % 100 {
         11 | 12 | 13 => "th",
         _ => match n % 10 {
             1 => "st",
@@ -126,14 +181,38 @@ fn format_ordinal(n: i64) -> String {
             3 => "rd",
             _ => "th",
         },
-    };
-    format!("{}{}", n, suffix)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_variable_ordinal() {
+        assert_eq!(parse("n th"), Some("nth".to_string()));
+        assert_eq!(parse("k th"), Some("kth".to_string()));
+        // Already written form passes through
+        assert_eq!(parse("nth"), None);
+        assert_eq!(parse("b th"), None);
+    }
+
+    #[test]
+    fn test_superscript() {
+        let options = NormalizeOptions {
+            ordinal_superscript: true,
+            ..Default::default()
+        };
+        let parse_sup = |s: &str| parse_with_options(s, &options);
+        assert_eq!(parse_sup("first"), Some("1ˢᵗ".to_string()));
+        assert_eq!(parse_sup("twenty first"), Some("21ˢᵗ".to_string()));
+        assert_eq!(parse_sup("second"), Some("2ⁿᵈ".to_string()));
+        assert_eq!(parse_sup("third"), Some("3ʳᵈ".to_string()));
+        assert_eq!(parse_sup("eleventh"), Some("11ᵗʰ".to_string()));
+        assert_eq!(parse_sup("one hundredth"), Some("100ᵗʰ".to_string()));
+        assert_eq!(parse("first"), Some("1st".to_string()));
+    }
+
     #[test]
     fn test_ones() {
         assert_eq!(parse("first"), Some("1st".to_string()));
@@ -165,11 +244,28 @@ mod tests {
         assert_eq!(parse("one hundred first"), Some("101st".to_string()));
         assert_eq!(parse("one hundred eleventh"), Some("111th".to_string()));
         assert_eq!(parse("one hundred twenty first"), Some("121st".to_string()));
+        assert_eq!(parse("one hundred and first"), Some("101st".to_string()));
+        assert_eq!(parse("two thousand and third"), Some("2003rd".to_string()));
+        assert_eq!(parse("one hundred and fiftieth"), Some("150th".to_string()));
+        assert_eq!(parse("and first"), None);
     }
 
     #[test]
     fn test_thousands() {
         assert_eq!(parse("one thousandth"), Some("1000th".to_string()));
+        assert_eq!(parse("one thousand first"), Some("1001st".to_string()));
+        assert_eq!(
+            parse("two thousand twenty third"),
+            Some("2023rd".to_string())
+        );
+        assert_eq!(
+            parse("two thousand and twenty third"),
+            Some("2023rd".to_string())
+        );
+        assert_eq!(
+            parse("one million and first"),
+            Some("1000001st".to_string())
+        );
         assert_eq!(
             parse("eleven hundred twenty first"),
             Some("1121st".to_string())
@@ -5,9 +5,13 @@
 //! - "comma" → ","
 //! - "question mark" → "?"
 //! - "exclamation point" → "!"
+//! - "open bracket five close bracket" → "[5]"
 
 use lazy_static::lazy_static;
 
+use super::cardinal::words_to_number;
+use crate::NormalizeOptions;
+
 lazy_static! {
     /// Spoken punctuation → written symbol mappings.
     /// Ordered longest-first so multi-word patterns match before single-word ones.
@@ -61,11 +65,24 @@ lazy_static! {
 /// Returns `Some(symbol)` if the entire input matches a known punctuation word.
 /// Only matches exact full input — does not replace within sentences.
 pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Parse spoken punctuation, applying rendering options
+/// (e.g. "ellipsis" → "…" when `ellipsis_char` is set).
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
     let input_lower = input.to_lowercase();
     let input_trimmed = input_lower.trim();
 
+    if let Some(result) = parse_citation(input_trimmed) {
+        return Some(result);
+    }
+
     for (pattern, symbol) in PUNCTUATION.iter() {
         if input_trimmed == *pattern {
+            if *pattern == "ellipsis" && options.ellipsis_char {
+                return Some("…".to_string());
+            }
             return Some(symbol.to_string());
         }
     }
@@ -73,6 +90,19 @@ pub fn parse(input: &str) -> Option<String> {
     None
 }
 
+/// Parse a bracketed citation number without internal spaces:
+/// "open bracket twelve close bracket" → "[12]"
+fn parse_citation(input: &str) -> Option<String> {
+    let inner = input
+        .strip_prefix("open bracket ")
+        .or_else(|| input.strip_prefix("left bracket "))?;
+    let number = inner
+        .strip_suffix(" close bracket")
+        .or_else(|| inner.strip_suffix(" right bracket"))?;
+
+    Some(format!("[{}]", words_to_number(number)?))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +115,19 @@ mod tests {
         assert_eq!(parse("semicolon"), Some(";".to_string()));
     }
 
+    #[test]
+    fn test_citation() {
+        assert_eq!(
+            parse("open bracket five close bracket"),
+            Some("[5]".to_string())
+        );
+        assert_eq!(
+            parse("left bracket twelve right bracket"),
+            Some("[12]".to_string())
+        );
+        assert_eq!(parse("open bracket see above close bracket"), None);
+    }
+
     #[test]
     fn test_multi_word() {
         assert_eq!(parse("question mark"), Some("?".to_string()));
@@ -115,6 +158,27 @@ mod tests {
         assert_eq!(parse("ellipsis"), Some("...".to_string()));
     }
 
+    #[test]
+    fn test_ellipsis_char() {
+        let options = NormalizeOptions {
+            ellipsis_char: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("ellipsis", &options),
+            Some("…".to_string())
+        );
+        assert_eq!(
+            parse_with_options("ellipsis", &NormalizeOptions::default()),
+            Some("...".to_string())
+        );
+        // Other symbols are unaffected
+        assert_eq!(
+            parse_with_options("period", &options),
+            Some(".".to_string())
+        );
+    }
+
     #[test]
     fn test_no_match() {
         assert_eq!(parse("hello"), None);
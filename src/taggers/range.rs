@@ -0,0 +1,264 @@
+//! Range tagger.
+//!
+//! Converts spoken ranges to written form, dispatching each endpoint to the
+//! tagger that understands it:
+//! - "between ten and twenty" → "10-20"
+//! - "five to ten kilometers" → "5-10 km"
+//! - "ten to twenty percent" → "10-20 %"
+//! - "from nine to five o'clock" → "09:00-17:00"
+//! - "between five and ten dollars" → "$5-$10"
+//!
+//! The separator is `NormalizeOptions::range_separator`.
+
+use super::cardinal::words_to_number;
+use super::{date, decimal, measure, money};
+use crate::NormalizeOptions;
+
+/// Parse spoken range expression to written form.
+pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Parse spoken range expression, applying output options.
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
+    let input_lower = input.trim().to_lowercase();
+
+    // Only an o'clock range consumes "from"; elsewhere the word stays in the
+    // text ("count from one to ten")
+    if let Some(body) = input_lower.strip_prefix("from ") {
+        return find_split(body, " to ", |low, high| {
+            parse_oclock_range(low, high, options.range_separator)
+        });
+    }
+
+    let (body, separator) = match input_lower.strip_prefix("between ") {
+        Some(body) => (body, " and "),
+        None => (input_lower.as_str(), " to "),
+    };
+    find_split(body, separator, |low, high| {
+        parse_endpoints(low, high, options)
+    })
+}
+
+/// Try every split of `body` at `separator`, rightmost first, and return the
+/// first that `parse` accepts. Going right to left, "between one hundred and
+/// twenty and two hundred" tries "one hundred and twenty" as the lower
+/// endpoint before "one hundred".
+fn find_split(
+    body: &str,
+    separator: &str,
+    mut parse: impl FnMut(&str, &str) -> Option<String>,
+) -> Option<String> {
+    body.rmatch_indices(separator).find_map(|(idx, _)| {
+        let low = body[..idx].trim();
+        let high = body[idx + separator.len()..].trim();
+        parse(low, high)
+    })
+}
+
+/// Parse both endpoints with the same tagger and join them.
+///
+/// The unit or currency is often only spoken on the upper endpoint ("five to
+/// ten dollars"), so the lower endpoint borrows it when needed.
+fn parse_endpoints(low: &str, high: &str, options: &NormalizeOptions) -> Option<String> {
+    if low.is_empty() || high.is_empty() {
+        return None;
+    }
+
+    if let Some(result) = parse_oclock_range(low, high, options.range_separator) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_number_range(low, high, options.range_separator) {
+        return Some(result);
+    }
+
+    let taggers: [fn(&str, &NormalizeOptions) -> Option<String>; 3] = [
+        money::parse_with_options,
+        measure::parse_with_options,
+        |s, _| date::parse(s),
+    ];
+    let high_words: Vec<&str> = high.split_whitespace().collect();
+
+    for tagger in taggers {
+        let Some(high_written) = tagger(high, options) else {
+            continue;
+        };
+
+        let borrowed = (1..high_words.len()).map(|k| {
+            let unit = high_words[high_words.len() - k..].join(" ");
+            format!("{} {}", low, unit)
+        });
+        let low_written = std::iter::once(low.to_string())
+            .chain(borrowed)
+            .find_map(|candidate| tagger(&candidate, options));
+
+        if let Some(low_written) = low_written {
+            return Some(join_range(
+                &low_written,
+                &high_written,
+                options.range_separator,
+            ));
+        }
+    }
+
+    None
+}
+
+/// Parse an o'clock range of hours. An end hour before the start is read as
+/// afternoon, as in business hours: "nine to five o'clock" → "09:00-17:00".
+fn parse_oclock_range(low: &str, high: &str, separator: char) -> Option<String> {
+    let high = high
+        .strip_suffix(" o'clock")
+        .or_else(|| high.strip_suffix(" oclock"))?;
+    let low = low
+        .strip_suffix(" o'clock")
+        .or_else(|| low.strip_suffix(" oclock"))
+        .unwrap_or(low);
+
+    let start = words_to_number(low)?;
+    let end = words_to_number(high)?;
+    if !(1..=12).contains(&start) || !(1..=12).contains(&end) || start == end {
+        return None;
+    }
+    let end = if end < start { end + 12 } else { end };
+    Some(format!("{:02}:00{}{:02}:00", start, separator, end))
+}
+
+/// Parse a plain numeric range. The bounds must ascend, which keeps phrases
+/// like "one to one" and "between one hundred and twenty" out.
+fn parse_number_range(low: &str, high: &str, separator: char) -> Option<String> {
+    let low = parse_number(low)?;
+    let high = parse_number(high)?;
+    if low.parse::<f64>().ok()? >= high.parse::<f64>().ok()? {
+        return None;
+    }
+    Some(format!("{}{}{}", low, separator, high))
+}
+
+/// Parse a bare cardinal or decimal endpoint
+fn parse_number(input: &str) -> Option<String> {
+    if input.split_whitespace().any(|w| w == "point") {
+        return decimal::parse(input);
+    }
+    words_to_number(input).map(|n| n.to_string())
+}
+
+/// Join written endpoints, sharing a common trailing unit: "5 km" and
+/// "10 km" → "5-10 km", "10%" and "20%" → "10-20%"
+fn join_range(low: &str, high: &str, separator: char) -> String {
+    if let (Some((low_value, low_unit)), Some((_, high_unit))) =
+        (low.split_once(' '), high.split_once(' '))
+    {
+        if low_unit == high_unit {
+            return format!("{}{}{}", low_value, separator, high);
+        }
+    }
+    if let (Some(low_value), true) = (low.strip_suffix('%'), high.ends_with('%')) {
+        return format!("{}{}{}", low_value, separator, high);
+    }
+    format!("{}{}{}", low, separator, high)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_between() {
+        assert_eq!(parse("between ten and twenty"), Some("10-20".to_string()));
+        assert_eq!(
+            parse("between one hundred and two hundred"),
+            Some("100-200".to_string())
+        );
+        assert_eq!(
+            parse("between two point five and three point five"),
+            Some("2.5-3.5".to_string())
+        );
+        assert_eq!(
+            parse("between one hundred and twenty and two hundred"),
+            Some("120-200".to_string())
+        );
+        assert_eq!(
+            parse("between one hundred and two hundred and fifty"),
+            Some("100-250".to_string())
+        );
+    }
+
+    #[test]
+    fn test_to() {
+        assert_eq!(parse("ten to twenty"), Some("10-20".to_string()));
+        assert_eq!(parse("five to ten kilometers"), Some("5-10 km".to_string()));
+        assert_eq!(
+            parse("between five and ten dollars"),
+            Some("$5-$10".to_string())
+        );
+    }
+
+    #[test]
+    fn test_percent_range() {
+        assert_eq!(parse("ten to twenty percent"), Some("10-20 %".to_string()));
+        assert_eq!(
+            parse("between five and ten percent"),
+            Some("5-10 %".to_string())
+        );
+
+        let options = NormalizeOptions {
+            percent_space: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("ten to twenty percent", &options),
+            Some("10-20%".to_string())
+        );
+    }
+
+    #[test]
+    fn test_oclock_range() {
+        assert_eq!(
+            parse("from nine to five o'clock"),
+            Some("09:00-17:00".to_string())
+        );
+        assert_eq!(
+            parse("between two and four o'clock"),
+            Some("02:00-04:00".to_string())
+        );
+        assert_eq!(
+            parse("nine o'clock to eleven o'clock"),
+            Some("09:00-11:00".to_string())
+        );
+        assert_eq!(parse("five to five o'clock"), None);
+        // "from" belongs to the range only for o'clock hours
+        assert_eq!(parse("from one to ten"), None);
+        assert_eq!(parse("from five to ten dollars"), None);
+        assert_eq!(parse("nine to fifteen o'clock"), None);
+    }
+
+    #[test]
+    fn test_range_separator() {
+        let options = NormalizeOptions {
+            range_separator: '–',
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("between five and ten dollars", &options),
+            Some("$5–$10".to_string())
+        );
+        assert_eq!(
+            parse_with_options("may first to may fifth", &options),
+            Some("may 1–may 5".to_string())
+        );
+        assert_eq!(
+            parse_with_options("five to ten kilometers", &options),
+            Some("5–10 km".to_string())
+        );
+    }
+
+    #[test]
+    fn test_no_match() {
+        assert_eq!(parse("one to one"), None);
+        assert_eq!(parse("between one hundred and twenty"), None);
+        assert_eq!(parse("between you and me"), None);
+        assert_eq!(parse("go to school"), None);
+    }
+}
@@ -0,0 +1,781 @@
+//! Reference number tagger.
+//!
+//! Converts a document cue followed by a spoken number to written form:
+//! - "check number one zero zero five" → "check #1005"
+//! - "invoice number two three four five" → "invoice #2345"
+//! - "p o number four two" → "PO #42"
+//! - "apartment four oh two" → "apartment 402"
+//! - "gate b twenty" → "gate B20", "seat fourteen c" → "seat 14C"
+//! - "flight two oh five" → "flight 205"
+//! - "isbn zero eight zero four four two nine five seven x" → "ISBN 080442957X"
+//! - "size ten and a half" → "size 10.5"
+//! - "act two scene three" → "act 2 scene 3" ("Act II Scene 3" with roman acts)
+//! - "step one" → "step 1", or "Step 1" with `title_case_steps`
+//! - "number five" → "#5" or "No. 5" (with a number prefix style)
+//! - "heading two seven zero degrees" → "heading 270°"
+//! - "latitude minus thirty three point eight six eight" → "latitude -33.868"
+//! - "world war two" → "World War II" (with roman after cue)
+//! - "firmware one point oh point two" → "firmware 1.0.2"
+
+use super::cardinal::{split_sign, words_to_number};
+use super::decimal::{self, parse_mixed_number};
+use crate::{NormalizeOptions, NumberPrefixStyle};
+
+/// Document cues that take a "number <digits>" reference: (spoken, written).
+/// `None` keeps the original spoken casing.
+const DOCUMENT_CUES: [(&str, Option<&str>); 6] = [
+    ("purchase order", None),
+    ("check", None),
+    ("invoice", None),
+    ("order", None),
+    ("p o", Some("PO")),
+    ("po", Some("PO")),
+];
+
+/// Address unit cues followed directly by a number ("apartment four oh two").
+const UNIT_CUES: [&str; 5] = ["apartment", "apt", "room", "suite", "unit"];
+
+/// Travel cues followed by a letter-and-number code ("gate b twenty").
+const SEAT_CUES: [&str; 2] = ["gate", "seat"];
+
+/// Enumeration cues followed by a number ("step one", "level three").
+const STEP_CUES: [&str; 4] = ["step", "phase", "level", "version"];
+
+/// Event cues whose number is written in Roman numerals: (spoken, written).
+const ROMAN_CUES: [(&str, &str); 2] = [("super bowl", "Super Bowl"), ("world war", "World War")];
+
+/// Navigation cues followed by a three-digit bearing in degrees. "course"
+/// is left out: "of course" is far more common than a spoken course.
+const HEADING_CUES: [&str; 2] = ["heading", "bearing"];
+
+/// Coordinate cues and the largest magnitude each allows, in degrees.
+const COORDINATE_CUES: [(&str, f64); 2] = [("latitude", 90.0), ("longitude", 180.0)];
+
+/// Spoken forms of a leading "number" prefix. "no" is left out: "no one"
+/// and "no two" are ordinary prose.
+const NUMBER_CUES: [&str; 2] = ["number", "num"];
+
+/// Cues followed by a dotted version number ("version two point one").
+const VERSION_CUES: [&str; 4] = ["version", "firmware", "software", "release"];
+
+/// Parse spoken reference number expression to written form.
+pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Parse spoken reference number expression, applying output options.
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
+    let original = input.trim();
+    let input_lower = original.to_lowercase();
+
+    if let Some(result) = parse_document_number(original, &input_lower) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_unit_number(original, &input_lower) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_seat_code(original, &input_lower) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_flight_number(original, &input_lower) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_isbn(&input_lower) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_size(original, &input_lower) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_act_scene(original, &input_lower, options) {
+        return Some(result);
+    }
+
+    if options.roman_after_cue {
+        if let Some(result) = parse_roman_after_cue(&input_lower) {
+            return Some(result);
+        }
+    }
+
+    if let Some(result) = parse_heading(original, &input_lower) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_coordinate(original, &input_lower, options) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_version(original, &input_lower) {
+        return Some(result);
+    }
+
+    if let Some(result) = parse_step(original, &input_lower, options) {
+        return Some(result);
+    }
+
+    if let Some(style) = options.number_prefix {
+        if let Some(result) = parse_number_prefix(&input_lower, style) {
+            return Some(result);
+        }
+    }
+
+    None
+}
+
+/// Parse "<doc> number <digits>" → "<doc> #<number>"
+fn parse_document_number(original: &str, input: &str) -> Option<String> {
+    for (cue, written) in &DOCUMENT_CUES {
+        let Some(rest) = input
+            .strip_prefix(cue)
+            .and_then(|r| r.strip_prefix(" number "))
+        else {
+            continue;
+        };
+
+        let number = parse_reference_digits(rest)?;
+        let cue_text = match written {
+            Some(w) => w.to_string(),
+            None => original.get(..cue.len())?.to_string(),
+        };
+        return Some(format!("{} #{}", cue_text, number));
+    }
+
+    None
+}
+
+/// Parse "<unit cue> <digits>" → "<unit cue> <number>"
+///
+/// Spoken leading zeros are kept ("room oh five" → "room 05") since they
+/// are part of the identifier, not a quantity. Only digit words are read:
+/// "unit" and "room" are common nouns, and "a room two hundred dollars a
+/// night" is a price.
+fn parse_unit_number(original: &str, input: &str) -> Option<String> {
+    let (cue, rest) = input.split_once(' ')?;
+    if !UNIT_CUES.contains(&cue) {
+        return None;
+    }
+
+    let number: String = rest
+        .split_whitespace()
+        .map(word_to_digit)
+        .collect::<Option<_>>()?;
+    Some(format!("{} {}", original.get(..cue.len())?, number))
+}
+
+/// Parse "<gate/seat> <letter> <digits>" or "<gate/seat> <digits> <letter>"
+/// → "gate B20", "seat 14C". "o" after the letter is a zero ("gate c o
+/// five" → "gate C05").
+fn parse_seat_code(original: &str, input: &str) -> Option<String> {
+    let (cue, rest) = input.split_once(' ')?;
+    if !SEAT_CUES.contains(&cue) {
+        return None;
+    }
+
+    // Codes are short digit runs; a scale word means an article and an
+    // amount ("the gate a hundred meters away")
+    if rest
+        .split_whitespace()
+        .any(|w| matches!(w, "hundred" | "thousand" | "million"))
+    {
+        return None;
+    }
+
+    let is_letter = |w: &str| w.len() == 1 && w.chars().all(|c| c.is_ascii_alphabetic());
+    let cue_text = original.get(..cue.len())?;
+    if let Some((letter, digits)) = rest.split_once(' ') {
+        // A leading "a" is read as an article: "seat a ten person group",
+        // "the gate a ten minute walk away". Row-first seats ("seat twelve
+        // a") still read.
+        let is_article = letter == "a";
+        if is_letter(letter) && !is_article {
+            let number = parse_reference_digits(digits)?;
+            return Some(format!("{} {}{}", cue_text, letter.to_uppercase(), number));
+        }
+    }
+
+    let (digits, letter) = rest.rsplit_once(' ')?;
+    if !is_letter(letter) {
+        return None;
+    }
+    let number = parse_reference_digits(digits)?;
+    Some(format!("{} {}{}", cue_text, number, letter.to_uppercase()))
+}
+
+/// Parse "flight <number>" → "flight 205". Flight numbers are read as
+/// digits ("two oh five") or in pairs ("twelve thirty four" → "1234"), up
+/// to four digits. A single number word is left alone, since "flight two
+/// hours" is more likely a duration.
+fn parse_flight_number(original: &str, input: &str) -> Option<String> {
+    let rest = input.strip_prefix("flight ")?;
+    let words: Vec<&str> = rest.split_whitespace().collect();
+    if words.len() < 2 {
+        return None;
+    }
+
+    let mut number = String::new();
+    let mut i = 0;
+    while i < words.len() {
+        if let Some(d) = word_to_digit(words[i]) {
+            number.push(d);
+            i += 1;
+            continue;
+        }
+
+        // A pair: "twelve", "thirty", "thirty four"
+        let pair = words_to_number(words[i]).filter(|n| (10..=99).contains(n))?;
+        let unit = words
+            .get(i + 1)
+            .and_then(|next| word_to_digit(next))
+            .filter(|&d| pair % 10 == 0 && pair >= 20 && d != '0');
+        number.push_str(&pair.to_string());
+        i += 1;
+        if let Some(d) = unit {
+            number.pop();
+            number.push(d);
+            i += 1;
+        }
+    }
+
+    if number.is_empty() || number.len() > 4 {
+        return None;
+    }
+    Some(format!("{} {}", original.get(..6)?, number))
+}
+
+/// Parse "size <number>" → "size <number>", allowing half sizes
+/// ("size ten and a half" → "size 10.5").
+fn parse_size(original: &str, input: &str) -> Option<String> {
+    let rest = input.strip_prefix("size ")?;
+    let size = match parse_mixed_number(rest) {
+        Some(size) => size,
+        None => words_to_number(rest)?.to_string(),
+    };
+    Some(format!("{} {}", original.get(..4)?, size))
+}
+
+/// Parse a play reference "act <n> scene <m>".
+///
+/// Scenes stay Arabic; acts become Roman numerals with `roman_acts`, which
+/// also title-cases the cue words as in printed scripts.
+fn parse_act_scene(original: &str, input: &str, options: &NormalizeOptions) -> Option<String> {
+    let rest = input.strip_prefix("act ")?;
+    let (act, scene) = rest.split_once(" scene ")?;
+    let act = words_to_number(act)?;
+    let scene = words_to_number(scene)?;
+    if act == 0 || scene == 0 {
+        return None;
+    }
+
+    if options.roman_acts {
+        return Some(format!("Act {} Scene {}", to_roman(act)?, scene));
+    }
+
+    let act_cue = original.get(..3)?;
+    let scene_start = original.to_lowercase().find(" scene ")? + 1;
+    let scene_cue = original.get(scene_start..scene_start + 5)?;
+    Some(format!("{} {} {} {}", act_cue, act, scene_cue, scene))
+}
+
+/// Parse "<heading cue> <d> <d> <d> degrees" → "heading 270°".
+///
+/// Bearings are spoken digit by digit; the cue keeps them apart from
+/// temperatures ("two seven zero degrees" alone is not a heading).
+fn parse_heading(original: &str, input: &str) -> Option<String> {
+    let (cue, rest) = input.split_once(' ')?;
+    if !HEADING_CUES.contains(&cue) {
+        return None;
+    }
+
+    let digits = rest.strip_suffix(" degrees")?;
+    if digits.split_whitespace().count() != 3 {
+        return None;
+    }
+    let bearing = parse_reference_digits(digits)?;
+    if bearing.len() != 3 || bearing.parse::<u32>().ok()? > 360 {
+        return None;
+    }
+    Some(format!("{} {}°", original.get(..cue.len())?, bearing))
+}
+
+/// Parse "<coordinate cue> <signed number> [degrees]" → "latitude -33.868".
+///
+/// A spoken "degrees" always gives "°"; otherwise it is added only with the
+/// coordinate degrees option. Values beyond ±90 (latitude) or ±180
+/// (longitude) are rejected.
+fn parse_coordinate(original: &str, input: &str, options: &NormalizeOptions) -> Option<String> {
+    let (cue, rest) = input.split_once(' ')?;
+    let &(_, max) = COORDINATE_CUES.iter().find(|(c, _)| *c == cue)?;
+
+    let (rest, spoken_degrees) = match rest.strip_suffix(" degrees") {
+        Some(rest) => (rest, true),
+        None => (rest, false),
+    };
+    let value = decimal::parse(rest).or_else(|| {
+        let (sign, number) = split_sign(rest);
+        words_to_number(number).map(|n| format!("{}{}", sign, n))
+    })?;
+    if value.parse::<f64>().ok()?.abs() > max {
+        return None;
+    }
+
+    let degree = if spoken_degrees || options.coordinate_degrees {
+        "°"
+    } else {
+        ""
+    };
+    Some(format!(
+        "{} {}{}",
+        original.get(..cue.len())?,
+        value,
+        degree
+    ))
+}
+
+/// Parse "<version cue> <n> point <n> ..." → "<cue> 1.0.2".
+///
+/// The cue lets any number of "point"/"dot" separators through, which
+/// neither the decimal tagger (one point) nor IP addresses (four octets)
+/// would accept. Each component is below 100, so a scale word still reads
+/// as an amount ("the software two point five million").
+fn parse_version(original: &str, input: &str) -> Option<String> {
+    let (cue, rest) = input.split_once(' ')?;
+    if !VERSION_CUES.contains(&cue) {
+        return None;
+    }
+
+    let rest = rest.replace(" dot ", " point ");
+    let parts: Vec<String> = rest
+        .split(" point ")
+        .map(|part| match part {
+            "o" | "oh" => Some("0".to_string()),
+            _ => words_to_number(part)
+                .filter(|&n| n < 100)
+                .map(|n| n.to_string()),
+        })
+        .collect::<Option<_>>()?;
+    if parts.len() < 2 {
+        return None;
+    }
+
+    Some(format!(
+        "{} {}",
+        original.get(..cue.len())?,
+        parts.join(".")
+    ))
+}
+
+/// Parse "<step cue> <number>" → "step 1". With `title_case_steps` the cue
+/// is title-cased as in numbered instructions: "Step 1".
+fn parse_step(original: &str, input: &str, options: &NormalizeOptions) -> Option<String> {
+    let (cue, rest) = input.split_once(' ')?;
+    if !STEP_CUES.contains(&cue) {
+        return None;
+    }
+
+    let number = words_to_number(rest)?;
+    if !options.title_case_steps {
+        return Some(format!("{} {}", original.get(..cue.len())?, number));
+    }
+    let mut chars = cue.chars();
+    let first = chars.next()?.to_uppercase();
+    Some(format!("{}{} {}", first, chars.as_str(), number))
+}
+
+/// Parse "number <n>" → "#5" or "No. 5"
+fn parse_number_prefix(input: &str, style: NumberPrefixStyle) -> Option<String> {
+    let (cue, rest) = input.split_once(' ')?;
+    if !NUMBER_CUES.contains(&cue) {
+        return None;
+    }
+
+    let number = words_to_number(rest)?;
+    Some(match style {
+        NumberPrefixStyle::Hash => format!("#{}", number),
+        NumberPrefixStyle::Abbreviation => format!("No. {}", number),
+    })
+}
+
+/// Parse "<event cue> <number>" → "World War II"
+fn parse_roman_after_cue(input: &str) -> Option<String> {
+    for (cue, written) in &ROMAN_CUES {
+        let Some(rest) = input.strip_prefix(cue).and_then(|r| r.strip_prefix(' ')) else {
+            continue;
+        };
+        return Some(format!("{} {}", written, to_roman(words_to_number(rest)?)?));
+    }
+
+    None
+}
+
+/// Render 1-3999 as a Roman numeral
+fn to_roman(mut n: i128) -> Option<String> {
+    if !(1..4000).contains(&n) {
+        return None;
+    }
+    const NUMERALS: [(i128, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+    let mut result = String::new();
+    for (value, numeral) in NUMERALS {
+        while n >= value {
+            result.push_str(numeral);
+            n -= value;
+        }
+    }
+    Some(result)
+}
+
+/// Parse "isbn <digits>" as an ISBN-10 or ISBN-13.
+///
+/// ISBN-10 may end in the check character X, spoken as the letter "x".
+fn parse_isbn(input: &str) -> Option<String> {
+    let rest = input
+        .strip_prefix("isbn ")
+        .or_else(|| input.strip_prefix("i s b n "))?;
+    let rest = rest.strip_prefix("number ").unwrap_or(rest);
+
+    let words: Vec<&str> = rest.split_whitespace().collect();
+    let (last, body) = words.split_last()?;
+    let mut digits: String = body
+        .iter()
+        .map(|w| word_to_digit(w))
+        .collect::<Option<_>>()?;
+
+    match (digits.len() + 1, *last) {
+        (10, "x") => digits.push('X'),
+        (10 | 13, _) => digits.push(word_to_digit(last)?),
+        _ => return None,
+    }
+
+    Some(format!("ISBN {}", digits))
+}
+
+/// Parse a reference number: a digit sequence ("one zero zero five" → "1005",
+/// "oh" = 0) or, failing that, a cardinal ("twelve hundred" → "1200").
+fn parse_reference_digits(input: &str) -> Option<String> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    if words.is_empty() {
+        return None;
+    }
+
+    let digits: Option<String> = words.iter().map(|w| word_to_digit(w)).collect();
+    if let Some(digits) = digits {
+        return Some(digits);
+    }
+
+    words_to_number(input).map(|n| n.to_string())
+}
+
+/// Convert word to single digit
+fn word_to_digit(word: &str) -> Option<char> {
+    match word {
+        "zero" | "o" | "oh" => Some('0'),
+        "one" => Some('1'),
+        "two" => Some('2'),
+        "three" => Some('3'),
+        "four" => Some('4'),
+        "five" => Some('5'),
+        "six" => Some('6'),
+        "seven" => Some('7'),
+        "eight" => Some('8'),
+        "nine" => Some('9'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_number() {
+        assert_eq!(
+            parse("check number one zero zero five"),
+            Some("check #1005".to_string())
+        );
+        assert_eq!(
+            parse("Check number one oh oh five"),
+            Some("Check #1005".to_string())
+        );
+    }
+
+    #[test]
+    fn test_invoice_number() {
+        assert_eq!(
+            parse("invoice number two three four five"),
+            Some("invoice #2345".to_string())
+        );
+    }
+
+    #[test]
+    fn test_order_numbers() {
+        assert_eq!(
+            parse("order number twelve hundred"),
+            Some("order #1200".to_string())
+        );
+        assert_eq!(parse("p o number four two"), Some("PO #42".to_string()));
+        assert_eq!(
+            parse("purchase order number nine nine"),
+            Some("purchase order #99".to_string())
+        );
+    }
+
+    #[test]
+    fn test_unit_numbers() {
+        assert_eq!(
+            parse("apartment four oh two"),
+            Some("apartment 402".to_string())
+        );
+        assert_eq!(parse("room oh five"), Some("room 05".to_string()));
+        assert_eq!(parse("Suite one two"), Some("Suite 12".to_string()));
+        assert_eq!(parse("Suite twelve"), None);
+        assert_eq!(parse("room two hundred"), None);
+        assert_eq!(parse("apt one two b"), None);
+        assert_eq!(parse("apartment"), None);
+    }
+
+    #[test]
+    fn test_size() {
+        assert_eq!(parse("size ten and a half"), Some("size 10.5".to_string()));
+        assert_eq!(parse("size eleven"), Some("size 11".to_string()));
+        assert_eq!(parse("Size nine"), Some("Size 9".to_string()));
+        assert_eq!(parse("size large"), None);
+    }
+
+    #[test]
+    fn test_act_scene() {
+        assert_eq!(
+            parse("act two scene three"),
+            Some("act 2 scene 3".to_string())
+        );
+        assert_eq!(
+            parse("Act four Scene one"),
+            Some("Act 4 Scene 1".to_string())
+        );
+        assert_eq!(parse("act two"), None);
+
+        let options = NormalizeOptions {
+            roman_acts: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("act two scene three", &options),
+            Some("Act II Scene 3".to_string())
+        );
+        assert_eq!(
+            parse_with_options("act fourteen scene nine", &options),
+            Some("Act XIV Scene 9".to_string())
+        );
+    }
+
+    #[test]
+    fn test_step() {
+        assert_eq!(parse("step one"), Some("step 1".to_string()));
+        assert_eq!(parse("Step two"), Some("Step 2".to_string()));
+        assert_eq!(parse("level three"), Some("level 3".to_string()));
+        assert_eq!(parse("step up"), None);
+        assert_eq!(parse("level"), None);
+
+        let options = NormalizeOptions {
+            title_case_steps: true,
+            ..Default::default()
+        };
+        let parse_title = |s: &str| parse_with_options(s, &options);
+        assert_eq!(parse_title("step one"), Some("Step 1".to_string()));
+        assert_eq!(parse_title("level three"), Some("Level 3".to_string()));
+        assert_eq!(parse_title("phase twelve"), Some("Phase 12".to_string()));
+    }
+
+    #[test]
+    fn test_version() {
+        assert_eq!(
+            parse("firmware one point oh point two"),
+            Some("firmware 1.0.2".to_string())
+        );
+        assert_eq!(
+            parse("version two point one"),
+            Some("version 2.1".to_string())
+        );
+        assert_eq!(
+            parse("Version three dot ten dot four"),
+            Some("Version 3.10.4".to_string())
+        );
+        assert_eq!(parse("firmware one point"), None);
+        assert_eq!(parse("software two point five million"), None);
+        assert_eq!(parse("version one point two hundred"), None);
+        assert_eq!(parse("release notes"), None);
+    }
+
+    #[test]
+    fn test_number_prefix() {
+        assert_eq!(parse("number five"), None);
+
+        let hash = NormalizeOptions {
+            number_prefix: Some(NumberPrefixStyle::Hash),
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("number five", &hash),
+            Some("#5".to_string())
+        );
+        assert_eq!(
+            parse_with_options("num twenty one", &hash),
+            Some("#21".to_string())
+        );
+
+        let abbreviation = NormalizeOptions {
+            number_prefix: Some(NumberPrefixStyle::Abbreviation),
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("number five", &abbreviation),
+            Some("No. 5".to_string())
+        );
+        assert_eq!(parse_with_options("number of people", &abbreviation), None);
+        assert_eq!(parse_with_options("no one", &abbreviation), None);
+    }
+
+    #[test]
+    fn test_roman_after_cue() {
+        assert_eq!(parse("world war two"), None);
+
+        let options = NormalizeOptions {
+            roman_after_cue: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("super bowl fifty eight", &options),
+            Some("Super Bowl LVIII".to_string())
+        );
+        assert_eq!(
+            parse_with_options("world war two", &options),
+            Some("World War II".to_string())
+        );
+        assert_eq!(parse_with_options("world war", &options), None);
+        assert_eq!(parse_with_options("super bowl party", &options), None);
+    }
+
+    #[test]
+    fn test_flight_number() {
+        assert_eq!(parse("flight two oh five"), Some("flight 205".to_string()));
+        assert_eq!(
+            parse("Flight twelve thirty four"),
+            Some("Flight 1234".to_string())
+        );
+        assert_eq!(parse("flight forty two"), Some("flight 42".to_string()));
+        assert_eq!(parse("flight ninety"), None);
+        assert_eq!(parse("flight two hours"), None);
+        assert_eq!(parse("flight one two three four five"), None);
+        assert_eq!(parse("flight"), None);
+    }
+
+    #[test]
+    fn test_seat_code() {
+        assert_eq!(parse("gate b twenty"), Some("gate B20".to_string()));
+        assert_eq!(parse("gate c o five"), Some("gate C05".to_string()));
+        assert_eq!(parse("seat fourteen c"), Some("seat 14C".to_string()));
+        assert_eq!(parse("Seat twelve a"), Some("Seat 12A".to_string()));
+        assert_eq!(parse("gate twenty"), None);
+        assert_eq!(parse("seat belt"), None);
+        assert_eq!(parse("gate b"), None);
+        assert_eq!(parse("gate a hundred"), None);
+        assert_eq!(parse("seat a ten"), None);
+        assert_eq!(parse("gate a ten"), None);
+    }
+
+    #[test]
+    fn test_coordinate() {
+        assert_eq!(
+            parse("latitude minus thirty three point eight six eight"),
+            Some("latitude -33.868".to_string())
+        );
+        assert_eq!(
+            parse("Longitude one hundred fifty one point two zero nine three"),
+            Some("Longitude 151.2093".to_string())
+        );
+        assert_eq!(
+            parse("latitude forty degrees"),
+            Some("latitude 40°".to_string())
+        );
+        assert_eq!(parse("latitude ninety five point five"), None);
+        assert_eq!(parse("latitude"), None);
+
+        let options = NormalizeOptions {
+            coordinate_degrees: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options(
+                "latitude minus thirty three point eight six eight",
+                &options
+            ),
+            Some("latitude -33.868°".to_string())
+        );
+    }
+
+    #[test]
+    fn test_heading() {
+        assert_eq!(
+            parse("heading two seven zero degrees"),
+            Some("heading 270°".to_string())
+        );
+        assert_eq!(
+            parse("Bearing oh four five degrees"),
+            Some("Bearing 045°".to_string())
+        );
+        assert_eq!(parse("heading four seven zero degrees"), None);
+        assert_eq!(parse("heading twenty degrees"), None);
+        assert_eq!(parse("course one two three degrees"), None);
+        assert_eq!(parse("two seven zero degrees"), None);
+    }
+
+    #[test]
+    fn test_isbn() {
+        assert_eq!(
+            parse("isbn zero eight zero four four two nine five seven x"),
+            Some("ISBN 080442957X".to_string())
+        );
+        assert_eq!(
+            parse("i s b n zero three zero six four zero six one five two"),
+            Some("ISBN 0306406152".to_string())
+        );
+        assert_eq!(
+            parse("isbn nine seven eight zero three zero six four zero six one five seven"),
+            Some("ISBN 9780306406157".to_string())
+        );
+        // X is only a check character, and only for ISBN-10
+        assert_eq!(
+            parse("isbn nine seven eight zero three zero six four zero six one five x"),
+            None
+        );
+        assert_eq!(
+            parse("isbn zero eight zero x four two nine five seven one"),
+            None
+        );
+        assert_eq!(parse("isbn one two three"), None);
+    }
+
+    #[test]
+    fn test_no_match() {
+        assert_eq!(parse("check number"), None);
+        assert_eq!(parse("check the number"), None);
+        assert_eq!(parse("invoice number hello"), None);
+    }
+}
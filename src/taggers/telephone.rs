@@ -6,9 +6,31 @@
 //! - "one two three dot one two three dot o dot four o" → "123.123.0.40"
 
 use super::cardinal::words_to_number;
+use super::decimal::decimal_word_as_point;
+
+/// Parse an international number led by "plus", for sentence mode.
+///
+/// Every word after "plus" must be part of the number, so surrounding prose
+/// is never swallowed ("plus four four ... today"), and a bare country code
+/// is left alone ("two plus one").
+pub fn parse_international(input: &str) -> Option<String> {
+    let input_lower = input.trim().to_lowercase();
+    let rest = input_lower.strip_prefix("plus ")?;
+    let all_number_words = rest
+        .split_whitespace()
+        .all(|w| word_to_digit(w).is_some() || words_to_number(w).is_some() || w == "double");
+    if !all_number_words {
+        return None;
+    }
+    parse(input).filter(|result| result.contains(' '))
+}
 
 /// Parse spoken telephone/serial number to written form.
 pub fn parse(input: &str) -> Option<String> {
+    if let Some(result) = parse_short_code(input) {
+        return Some(result);
+    }
+
     let input_lower = input.to_lowercase();
     let input_trimmed = input_lower.trim();
 
@@ -17,6 +39,15 @@ pub fn parse(input: &str) -> Option<String> {
         return None;
     }
 
+    // "point" marks a spoken decimal ("three point one four", or "three
+    // decimal one four"), never a phone number or IP address, which use "dot"
+    if decimal_word_as_point(input_trimmed)
+        .split_whitespace()
+        .any(|w| w == "point")
+    {
+        return None;
+    }
+
     // Try IP address pattern first (contains "dot")
     if input_trimmed.contains(" dot ") {
         return parse_ip_address(input_trimmed);
@@ -27,6 +58,23 @@ pub fn parse(input: &str) -> Option<String> {
         return parse_ssn_in_context(input, input_trimmed);
     }
 
+    // Spoken "oh" stands for a single zero digit; before a teen or tens
+    // word it is a clock reading ("twelve oh fifteen"), not a number
+    let words: Vec<&str> = input_trimmed.split_whitespace().collect();
+    let oh_before_non_digit = words.windows(2).any(|pair| {
+        matches!(pair[0], "o" | "oh")
+            && word_to_digit(pair[1]).is_none()
+            && !matches!(pair[1], "double" | "triple")
+    });
+    if oh_before_non_digit {
+        return None;
+    }
+
+    // Try a standalone country code ("plus forty four" → "+44")
+    if let Some(result) = parse_country_code(input_trimmed) {
+        return Some(result);
+    }
+
     // Try alphanumeric product/serial code patterns
     if let Some(result) = parse_alphanumeric_code(input) {
         return Some(result);
@@ -37,9 +85,15 @@ pub fn parse(input: &str) -> Option<String> {
         return None;
     }
 
-    // Don't match if input has scale words (billion, million, etc.)
+    // Don't match if input has scale words (billion, million, etc.),
+    // except for spoken area codes like "eight hundred"
     if has_scale_words(input_trimmed) {
-        return None;
+        return parse_hundred_area_code(input_trimmed);
+    }
+
+    // Try phone number with extension ("... and extension nine")
+    if let Some(result) = parse_with_extension(input_trimmed) {
+        return Some(result);
     }
 
     // Try phone number pattern
@@ -304,6 +358,15 @@ fn parse_alphanumeric_code(input: &str) -> Option<String> {
             }
         }
 
+        // A lone "a" or "i" before an ordinary word is an article or
+        // pronoun, not part of a code: "one a penny"
+        if i >= 1 && matches!(words[i - 1].to_lowercase().as_str(), "a" | "i") {
+            let after_letter = i >= 2 && is_single_letter(&words[i - 2].to_lowercase());
+            if !after_letter {
+                return None;
+            }
+        }
+
         // Unknown word - keep as-is with space if needed
         if !result.is_empty() && !result.ends_with(' ') {
             result.push(' ');
@@ -315,6 +378,11 @@ fn parse_alphanumeric_code(input: &str) -> Option<String> {
 
     // Flush remaining letters
     if !letter_run.is_empty() {
+        // Trailing letters are a suffix to a number (1080p). After an
+        // ordinary word they are prose, not a code: "ten past p m"
+        if !prev_was_number && !result.is_empty() {
+            return None;
+        }
         if should_uppercase_abbrev(&letter_run) {
             result.push_str(&letter_run.to_uppercase());
         } else {
@@ -372,10 +440,50 @@ fn should_join_letters_to_number(s: &str) -> bool {
     s == "x"
 }
 
+/// Parse phone number followed by an extension:
+/// "five five five one two three four and extension nine" → "555-1234 ext. 9"
+fn parse_with_extension(input: &str) -> Option<String> {
+    let (number, extension) = input
+        .split_once(" extension ")
+        .or_else(|| input.split_once(" ext "))?;
+    let number = number.strip_suffix(" and").unwrap_or(number);
+
+    let formatted = parse_phone_number(number)?;
+    let extension = parse_digit_sequence_with_double(extension)?;
+    Some(format!("{} ext. {}", formatted, extension))
+}
+
+/// Parse a service short code "<digit> one one" → "411", "911".
+///
+/// "nine eleven" is read as 911 too, since the emergency number is what it
+/// almost always means. Callers run this ahead of time, which would
+/// otherwise read it as 09:11.
+pub fn parse_short_code(input: &str) -> Option<String> {
+    let input = input.trim().to_lowercase();
+    if input == "nine eleven" {
+        return Some("911".to_string());
+    }
+    let first = input.strip_suffix(" one one")?;
+    match word_to_digit(first)? {
+        d @ '2'..='9' => Some(format!("{}11", d)),
+        _ => None,
+    }
+}
+
 /// Parse phone number
 fn parse_phone_number(input: &str) -> Option<String> {
     let has_plus = input.starts_with("plus ");
 
+    // "and" between digit groups joins two separate numbers, not one:
+    // "one two three and four five six" → "123 and 456"
+    if input.contains(" and ") {
+        let groups: Option<Vec<String>> = input.split(" and ").map(parse_phone_number).collect();
+        return groups.map(|groups| groups.join(" and "));
+    }
+    if input.split_whitespace().any(|w| w == "and") {
+        return None;
+    }
+
     // Parse prefix and digits
     let (prefix, rest) = extract_phone_prefix(input);
     let digits = parse_digit_sequence_with_double(rest)?;
@@ -395,6 +503,42 @@ fn parse_phone_number(input: &str) -> Option<String> {
     }
 }
 
+/// Parse phone numbers with a spoken "X hundred" area code:
+/// "one eight hundred five five five one two three four" → "1 800-555-1234"
+///
+/// Only a leading area code may use "hundred", and the remaining words must be
+/// plain digits so that cardinals like "two hundred five" are not captured.
+fn parse_hundred_area_code(input: &str) -> Option<String> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let idx = words.iter().position(|w| *w == "hundred")?;
+
+    // "eight hundred ..." or "one eight hundred ..."
+    if idx == 0 || idx > 2 {
+        return None;
+    }
+    if !words[..idx].iter().all(|w| word_to_digit(w).is_some()) {
+        return None;
+    }
+
+    let rest = &words[idx + 1..];
+    let is_digit_run = rest
+        .iter()
+        .all(|w| word_to_digit(w).is_some() || *w == "double" || *w == "triple");
+    if rest.is_empty() || !is_digit_run {
+        return None;
+    }
+
+    let expanded = format!("{} zero zero {}", words[..idx].join(" "), rest.join(" "));
+    let digits = parse_digit_sequence_with_double(&expanded)?;
+
+    // Area code only makes sense as part of a full number
+    if digits.len() != 10 && digits.len() != 11 {
+        return None;
+    }
+
+    Some(format_phone_number(&digits))
+}
+
 /// Check if word is a tens word (twenty, thirty, etc.)
 fn is_tens_word(word: &str) -> bool {
     matches!(
@@ -471,6 +615,15 @@ fn extract_phone_prefix(input: &str) -> (String, &str) {
     (format!("+{}", code), &input[remaining_start..])
 }
 
+/// Parse a country code spoken on its own: "plus forty four" → "+44"
+fn parse_country_code(input: &str) -> Option<String> {
+    let (prefix, rest) = extract_phone_prefix(input);
+    if prefix.is_empty() || !rest.trim().is_empty() {
+        return None;
+    }
+    Some(prefix)
+}
+
 /// Parse digit sequence handling "double X" patterns
 fn parse_digit_sequence_with_double(input: &str) -> Option<String> {
     let words: Vec<&str> = input.split_whitespace().collect();
@@ -699,6 +852,120 @@ mod tests {
         assert_eq!(parse("seven nine nine"), Some("799".to_string()));
     }
 
+    #[test]
+    fn test_rejects_point_decimals() {
+        assert_eq!(parse("three point one four"), None);
+        assert_eq!(parse("pi is three point one four"), None);
+        assert_eq!(parse("one two three point four five six"), None);
+    }
+
+    #[test]
+    fn test_dictated_separators() {
+        assert_eq!(
+            parse("one two three dash four five six seven"),
+            Some("123-4567".to_string())
+        );
+        assert_eq!(
+            parse("five five five hyphen one two three four"),
+            Some("555-1234".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rejects_decimal_word() {
+        assert_eq!(parse("three decimal one four"), None);
+        assert_eq!(parse("he said three decimal one four times"), None);
+    }
+
+    #[test]
+    fn test_and_in_sequences() {
+        assert_eq!(
+            parse("five five five one two three four and extension nine"),
+            Some("555-1234 ext. 9".to_string())
+        );
+        assert_eq!(
+            parse("five five five one two three four extension four two"),
+            Some("555-1234 ext. 42".to_string())
+        );
+        // Two separate numbers are not merged into one
+        assert_eq!(
+            parse("one two three and four five six"),
+            Some("123 and 456".to_string())
+        );
+        assert_eq!(parse("one two three and four"), None);
+    }
+
+    #[test]
+    fn test_country_codes() {
+        assert_eq!(parse("plus one"), Some("+1".to_string()));
+        assert_eq!(parse("plus forty four"), Some("+44".to_string()));
+        assert_eq!(parse("plus nine one"), Some("+91".to_string()));
+        assert_eq!(parse("plus"), None);
+        assert_eq!(parse("plus size"), None);
+    }
+
+    #[test]
+    fn test_international() {
+        assert_eq!(
+            parse_international("plus forty four one two three one two three five six seven eight"),
+            Some("+44 123-123-5678".to_string())
+        );
+        assert_eq!(
+            parse_international(
+                "plus forty four one two three one two three five six seven eight today"
+            ),
+            None
+        );
+        assert_eq!(parse_international("plus one"), None);
+    }
+
+    #[test]
+    fn test_oh_before_non_digit() {
+        assert_eq!(parse("twelve oh fifteen"), None);
+        assert_eq!(
+            parse("five five five oh one two three"),
+            Some("555-0123".to_string())
+        );
+        assert_eq!(parse_international("one two three"), None);
+    }
+
+    #[test]
+    fn test_article_before_word() {
+        assert_eq!(parse("one a penny"), None);
+        assert_eq!(parse("x eighty six"), Some("x86".to_string()));
+    }
+
+    #[test]
+    fn test_trailing_letters_after_word() {
+        assert_eq!(parse("ten past p m"), None);
+        assert_eq!(parse("ten eighty p"), Some("1080p".to_string()));
+    }
+
+    #[test]
+    fn test_short_codes() {
+        assert_eq!(parse("nine one one"), Some("911".to_string()));
+        assert_eq!(parse("four one one"), Some("411".to_string()));
+        assert_eq!(parse("nine eleven"), Some("911".to_string()));
+        assert_eq!(parse_short_code("Nine Eleven"), Some("911".to_string()));
+        assert_eq!(parse_short_code("seven eleven"), None);
+        assert_eq!(parse_short_code("one one one"), None);
+    }
+
+    #[test]
+    fn test_hundred_area_code() {
+        assert_eq!(
+            parse("one eight hundred five five five one two three four"),
+            Some("1 800-555-1234".to_string())
+        );
+        assert_eq!(
+            parse("eight hundred five five five one two three four"),
+            Some("800-555-1234".to_string())
+        );
+        // Plain cardinals with "hundred" are not phone numbers
+        assert_eq!(parse("nine hundred"), None);
+        assert_eq!(parse("two hundred five"), None);
+    }
+
     #[test]
     fn test_ip_address() {
         assert_eq!(
@@ -5,11 +5,51 @@
 //! - "two thirty pm" → "02:30 p.m."
 //! - "quarter past one" → "01:15"
 //! - "half past three" → "03:30"
+//! - "half ten" → "10:30" (British time option)
+//! - "oh eight hundred hours" → "08:00" (military time option)
+//! - "fourteen hundred" → "14:00" (military time option, whole input only)
+//! - "ran one forty five point three" → "ran 1:45.3" (race time, after a cue)
 
-use super::cardinal::words_to_number;
+use super::cardinal::{split_sign, words_to_number};
+use crate::NormalizeOptions;
+
+/// Words that introduce a race time ("ran one forty five point three").
+/// Without one, "seven fifty point two" is more likely a decimal.
+const RACE_CUES: [&str; 5] = ["ran", "time", "lap", "in", "clocked"];
 
 /// Parse spoken time expression to written form.
 pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Whether the input is a clock time with a sign: "minus two thirty". A
+/// clock time is never negative, so the phrase is best kept as spoken
+/// rather than read as some other number.
+pub fn is_signed_time(input: &str) -> bool {
+    let lower = input.trim().to_lowercase();
+    let (sign, rest) = split_sign(&lower);
+    !sign.is_empty() && parse(rest).is_some()
+}
+
+/// Parse spoken time expression, applying output options.
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
+    // A clock time is never negative; "minus five minutes" is an offset
+    // and belongs to measure
+    let lower = input.trim_start().to_lowercase();
+    if lower.starts_with("minus ") || lower.starts_with("negative ") {
+        return None;
+    }
+
+    if options.military_time {
+        if let Some(result) = parse_military_hours(input) {
+            return Some(result);
+        }
+    }
+
+    if let Some(result) = parse_race_time(input.trim(), lower.trim()) {
+        return Some(result);
+    }
+
     let original = input.trim();
     let input_lower = original.to_lowercase();
 
@@ -21,6 +61,12 @@ pub fn parse(input: &str) -> Option<String> {
         return Some(result);
     }
 
+    if options.british_time {
+        if let Some(result) = parse_british_half(&time_part, &period, &timezone) {
+            return Some(result);
+        }
+    }
+
     if let Some(result) = parse_oclock(&time_part, &period, &timezone) {
         return Some(result);
     }
@@ -160,19 +206,33 @@ fn format_time(hour: i64, minute: i64, period: &str, timezone: &str) -> String {
 fn parse_quarter_half(input: &str, period: &str, timezone: &str) -> Option<String> {
     if input.starts_with("quarter past ") {
         let hour_part = input.trim_start_matches("quarter past ");
-        let hour = words_to_number(hour_part)? as i64;
+        let hour = parse_clock_hour(hour_part)?;
         return Some(format_time(hour, 15, period, timezone));
     }
 
     if input.starts_with("half past ") {
         let hour_part = input.trim_start_matches("half past ");
-        let hour = words_to_number(hour_part)? as i64;
+        let hour = parse_clock_hour(hour_part)?;
         return Some(format_time(hour, 30, period, timezone));
     }
 
     None
 }
 
+/// Parse British "half <hour>", short for half past: "half ten" → "10:30"
+fn parse_british_half(input: &str, period: &str, timezone: &str) -> Option<String> {
+    let hour_part = input.strip_prefix("half ")?;
+    let hour = parse_clock_hour(hour_part)?;
+    Some(format_time(hour, 30, period, timezone))
+}
+
+/// Parse the hour of a "past"/"to" phrase, which must be a clock hour
+/// (1-24): "half past thirty" and "quarter to zero" are not times.
+fn parse_clock_hour(input: &str) -> Option<i64> {
+    let hour = words_to_number(input)? as i64;
+    (1..=24).contains(&hour).then_some(hour)
+}
+
 /// Parse "X o'clock" pattern
 fn parse_oclock(input: &str, period: &str, timezone: &str) -> Option<String> {
     if input.ends_with(" o'clock") || input.ends_with(" oclock") {
@@ -190,7 +250,7 @@ fn parse_oclock(input: &str, period: &str, timezone: &str) -> Option<String> {
 fn parse_to_pattern(input: &str, period: &str, timezone: &str) -> Option<String> {
     if input.starts_with("quarter to ") {
         let hour_part = input.trim_start_matches("quarter to ");
-        let hour = words_to_number(hour_part)? as i64;
+        let hour = parse_clock_hour(hour_part)?;
         let prev_hour = if hour == 1 { 12 } else { hour - 1 };
         return Some(format_time(prev_hour, 45, period, timezone));
     }
@@ -205,7 +265,10 @@ fn parse_to_pattern(input: &str, period: &str, timezone: &str) -> Option<String>
                 .trim_end_matches(" minute")
                 .trim_end_matches(" minutes");
             let minutes_before = words_to_number(min_part)? as i64;
-            let hour = words_to_number(parts[1])? as i64;
+            if !(1..60).contains(&minutes_before) {
+                return None;
+            }
+            let hour = parse_clock_hour(parts[1])?;
             let prev_hour = if hour == 1 { 12 } else { hour - 1 };
             let minute = 60 - minutes_before;
             return Some(format_time(prev_hour, minute, period, timezone));
@@ -267,6 +330,144 @@ fn parse_standard_time(input: &str, period: &str, timezone: &str) -> Option<Stri
     }
 }
 
+/// Parse military time read with an explicit "hours" suffix:
+/// - "oh eight hundred hours" → "08:00"
+/// - "fourteen thirty hours" → "14:30"
+/// - "twenty three hundred hours" → "23:00"
+pub fn parse_military_hours(input: &str) -> Option<String> {
+    let input = input.trim().to_lowercase();
+    let clock = input.strip_suffix(" hours")?;
+    let (hour, minute) = parse_military_clock(clock)?;
+    Some(format_time(hour, minute, "", ""))
+}
+
+/// Parse a bare military clock reading, without "hours":
+/// - "fourteen hundred" → "14:00"
+/// - "zero nine thirty" → "09:30"
+/// - "twenty three hundred" → "23:00"
+///
+/// Readings past "twenty four hundred" are rejected. Without the "hours"
+/// cue this is easily a plain number ("fourteen hundred people"), so
+/// callers should only try it on a whole input.
+pub fn parse_military(input: &str) -> Option<String> {
+    let input = input.trim().to_lowercase();
+    let (hour, minute) = parse_military_clock(&input)?;
+    Some(format_time(hour, minute, "", ""))
+}
+
+/// Parse a four-digit military clock reading into (hour, minute).
+///
+/// The hour is either "oh"/"zero" + digit ("oh eight") or a number up to
+/// twenty four; the minutes are "hundred" (on the hour) or a minute reading.
+fn parse_military_clock(input: &str) -> Option<(i64, i64)> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    if words.len() < 2 {
+        return None;
+    }
+
+    // Zero-padded hour: "oh eight", "zero nine"
+    let zero_padded = if matches!(words[0], "oh" | "o" | "zero") {
+        let digit = words_to_number(words[1]).or(match words[1] {
+            "oh" | "o" => Some(0),
+            _ => None,
+        })?;
+        if digit > 9 {
+            return None;
+        }
+        Some((digit as i64, 2))
+    } else {
+        None
+    };
+
+    // Otherwise try a two-word hour ("twenty three") before a one-word hour.
+    // Hours before ten must be zero-padded: "one hundred hours" is a
+    // duration, "oh one hundred hours" is 01:00.
+    let candidates = match zero_padded {
+        Some(hour) => vec![hour],
+        None => (1..=words.len().min(2))
+            .rev()
+            .filter_map(|n| {
+                let hour = words_to_number(&words[..n].join(" "))? as i64;
+                (hour >= 10).then_some((hour, n))
+            })
+            .collect(),
+    };
+
+    for (hour, consumed) in candidates {
+        let rest = &words[consumed..];
+        if rest.is_empty() || hour > 24 {
+            continue;
+        }
+
+        let minute = if rest == ["hundred"] {
+            0
+        } else {
+            match parse_minute(&rest.join(" ")) {
+                Some(m) if (10..60).contains(&m) || rest[0] == "oh" || rest[0] == "o" => m,
+                _ => continue,
+            }
+        };
+
+        if hour == 24 && minute != 0 {
+            continue;
+        }
+        return Some((hour, minute));
+    }
+
+    None
+}
+
+/// Parse a race time "<cue> <minutes> <seconds> point <tenths>":
+/// "ran one forty five point three" → "ran 1:45.3"
+///
+/// The seconds must be a two-digit reading ("forty five", "oh five"), which
+/// keeps plain decimals like "forty five point three" out.
+fn parse_race_time(original: &str, input: &str) -> Option<String> {
+    let (cue, rest) = input.split_once(' ')?;
+    if !RACE_CUES.contains(&cue) {
+        return None;
+    }
+    let cue_text = original.get(..cue.len())?;
+    let (clock, fraction) = rest.split_once(" point ")?;
+
+    let fraction: String = fraction
+        .split_whitespace()
+        .map(|w| match w {
+            "oh" | "o" => Some('0'),
+            _ => match words_to_number(w)? {
+                n @ 0..=9 => char::from_digit(n as u32, 10),
+                _ => None,
+            },
+        })
+        .collect::<Option<_>>()?;
+    if fraction.is_empty() {
+        return None;
+    }
+
+    let words: Vec<&str> = clock.split_whitespace().collect();
+    for seconds_len in [2, 1] {
+        if words.len() <= seconds_len || words.len() - seconds_len > 2 {
+            continue;
+        }
+        let (minute_words, second_words) = words.split_at(words.len() - seconds_len);
+
+        let seconds = match parse_minute(&second_words.join(" ")) {
+            Some(s) if s >= 10 || matches!(second_words[0], "oh" | "o") => s,
+            _ => continue,
+        };
+        let minutes = match words_to_number(&minute_words.join(" ")) {
+            Some(m) if (1..60).contains(&m) => m,
+            _ => continue,
+        };
+        return Some(format!(
+            "{} {}:{:02}.{}",
+            cue_text, minutes, seconds, fraction
+        ));
+    }
+
+    None
+}
+
 /// Parse a simple hour word (one-twelve only)
 fn parse_simple_hour(word: &str) -> Option<i64> {
     match word {
@@ -374,6 +575,60 @@ mod tests {
         assert_eq!(parse("half past twelve"), Some("12:30".to_string()));
     }
 
+    #[test]
+    fn test_incomplete_phrases() {
+        assert_eq!(parse("half past"), None);
+        assert_eq!(parse("quarter past"), None);
+        assert_eq!(parse("quarter to"), None);
+        assert_eq!(parse("past ten"), None);
+        assert_eq!(parse("twenty to"), None);
+        assert_eq!(parse("quarter past the hour"), None);
+        assert_eq!(parse("half past past"), None);
+        assert_eq!(parse("half past thirty"), None);
+        assert_eq!(parse("quarter to zero"), None);
+        assert_eq!(parse("seventy to two"), None);
+    }
+
+    #[test]
+    fn test_military() {
+        assert_eq!(
+            parse_military("fourteen hundred"),
+            Some("14:00".to_string())
+        );
+        assert_eq!(
+            parse_military("zero nine thirty"),
+            Some("09:30".to_string())
+        );
+        assert_eq!(
+            parse_military("twenty three hundred"),
+            Some("23:00".to_string())
+        );
+        assert_eq!(
+            parse_military("twenty four hundred"),
+            Some("24:00".to_string())
+        );
+        assert_eq!(parse_military("twenty five hundred"), None);
+        assert_eq!(parse_military("twenty four thirty"), None);
+        assert_eq!(parse_military("nine hundred"), None);
+        assert_eq!(parse_military("fourteen"), None);
+    }
+
+    #[test]
+    fn test_british_half() {
+        assert_eq!(parse("half ten"), None);
+
+        let options = NormalizeOptions {
+            british_time: true,
+            ..Default::default()
+        };
+        let parse_british = |s: &str| parse_with_options(s, &options);
+        assert_eq!(parse_british("half ten"), Some("10:30".to_string()));
+        assert_eq!(parse_british("half two pm"), Some("02:30 p.m.".to_string()));
+        assert_eq!(parse_british("half past ten"), Some("10:30".to_string()));
+        assert_eq!(parse_british("half thirty"), None);
+        assert_eq!(parse_british("half"), None);
+    }
+
     #[test]
     fn test_quarter_to() {
         assert_eq!(parse("quarter to one"), Some("12:45".to_string()));
@@ -389,6 +644,9 @@ mod tests {
     fn test_oh_minutes() {
         assert_eq!(parse("eight o six"), Some("08:06".to_string()));
         assert_eq!(parse("twelve oh five"), Some("12:05".to_string()));
+        // "oh" only pads single-digit minutes
+        assert_eq!(parse("twelve oh fifteen"), None);
+        assert_eq!(parse("twelve oh twenty five"), None);
     }
 
     #[test]
@@ -397,6 +655,71 @@ mod tests {
         assert_eq!(parse("seven a m e s t"), Some("07:00 a.m. est".to_string()));
     }
 
+    #[test]
+    fn test_race_time() {
+        assert_eq!(
+            parse("ran one forty five point three"),
+            Some("ran 1:45.3".to_string())
+        );
+        assert_eq!(
+            parse("Lap twelve oh five point two one"),
+            Some("Lap 12:05.21".to_string())
+        );
+        assert_eq!(
+            parse("in twenty one thirty point five"),
+            Some("in 21:30.5".to_string())
+        );
+        // Plain decimals are not race times
+        assert_eq!(parse("ran forty five point three"), None);
+        assert_eq!(parse("in twenty one point five"), None);
+        // Without a cue the structure alone is not enough
+        assert_eq!(parse("one forty five point three"), None);
+        assert_eq!(parse("seven fifty point two"), None);
+    }
+
+    #[test]
+    fn test_rejects_negative() {
+        assert_eq!(parse("minus two thirty"), None);
+        assert_eq!(parse("minus two thirty pm"), None);
+        assert_eq!(parse("negative five o'clock"), None);
+        assert!(is_signed_time("minus two thirty"));
+        assert!(!is_signed_time("two thirty"));
+        assert!(!is_signed_time("minus five minutes"));
+    }
+
+    #[test]
+    fn test_military_hours() {
+        let options = NormalizeOptions {
+            military_time: true,
+            ..Default::default()
+        };
+        let parse_mil = |s: &str| parse_with_options(s, &options);
+        assert_eq!(
+            parse_mil("oh eight hundred hours"),
+            Some("08:00".to_string())
+        );
+        assert_eq!(
+            parse_mil("fourteen thirty hours"),
+            Some("14:30".to_string())
+        );
+        assert_eq!(
+            parse_mil("twenty three hundred hours"),
+            Some("23:00".to_string())
+        );
+        assert_eq!(
+            parse_mil("twenty four hundred hours"),
+            Some("24:00".to_string())
+        );
+        assert_eq!(parse_mil("twenty five hundred hours"), None);
+        // Unpadded hours before ten are durations
+        assert_eq!(parse_mil("oh one hundred hours"), Some("01:00".to_string()));
+        assert_eq!(parse_mil("one hundred hours"), None);
+        assert_eq!(parse_mil("a hundred hours"), None);
+        assert_eq!(parse_mil("nine thirty hours"), None);
+        // Off by default
+        assert_eq!(parse("oh eight hundred hours"), None);
+    }
+
     #[test]
     fn test_rejects_phone_like_input() {
         // These should NOT be parsed as time - they're phone numbers
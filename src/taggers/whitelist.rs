@@ -6,7 +6,10 @@
 //! - "for example" → "e.g."
 //! - "s and p five hundred" → "S&P 500"
 //! - "r t x" → "RTX"
+//! - "dot net" → ".NET"
+//! - "versus" → "vs."
 
+use crate::NormalizeOptions;
 use lazy_static::lazy_static;
 
 lazy_static! {
@@ -21,9 +24,14 @@ lazy_static! {
         ("cat five e", "CAT5e"),
         ("c u d n n", "cuDNN"),
         ("r t x", "RTX"),
+        ("dot net", ".NET"),
+        ("dot com", ".com"),
+        ("dot org", ".org"),
 
         // Phrases
         ("for example", "e.g."),
+        ("et cetera", "etc."),
+        ("versus", "vs."),
 
         // Titles (must come after longer patterns)
         ("doctor", "dr."),
@@ -33,32 +41,56 @@ lazy_static! {
     ];
 }
 
+/// Replacements too ambiguous for the default list, enabled by options
+fn optional_replacements(options: &NormalizeOptions) -> Vec<(&'static str, &'static str)> {
+    let mut replacements = Vec::new();
+    if options.that_is_abbreviation {
+        replacements.push(("that is", "i.e."));
+    }
+    replacements
+}
+
 /// Patterns that should only match when they're the complete input
 /// (abbreviations that might be part of larger alphanumeric codes)
 fn is_exact_match_only(pattern: &str) -> bool {
     matches!(
         pattern,
-        "r t x" | "p c i e x eight" | "cat five e" | "c u d n n"
+        "r t x"
+            | "p c i e x eight"
+            | "cat five e"
+            | "c u d n n"
+            | "dot net"
+            | "dot com"
+            | "dot org"
+            | "et cetera"
+            | "versus"
+            | "that is"
     )
 }
 
 /// Apply whitelist replacements to input text, preserving original casing where possible.
 /// Returns Some if any replacement was made, None otherwise.
 pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Apply whitelist replacements, including those enabled by options.
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
     let input_lower = input.to_lowercase();
     let input_trimmed = input_lower.trim();
     let mut result = input.to_string(); // Keep original casing
     let mut made_replacement = false;
 
-    for (pattern, replacement) in REPLACEMENTS.iter() {
+    let optional = optional_replacements(options);
+    for (pattern, replacement) in REPLACEMENTS.iter().chain(optional.iter()) {
         if is_exact_match_only(pattern) {
             // Only match if this is the complete input
             if input_trimmed == *pattern {
                 return Some(replacement.to_string());
             }
-        } else if input_lower.contains(pattern) {
+        } else if let Some(replaced) = replace_preserve_case(&result, pattern, replacement) {
             // Find the pattern case-insensitively and replace with case-aware replacement
-            result = replace_preserve_case(&result, pattern, replacement);
+            result = replaced;
             made_replacement = true;
         }
     }
@@ -70,10 +102,20 @@ pub fn parse(input: &str) -> Option<String> {
     }
 }
 
-/// Replace pattern preserving the first letter's case from the original
-fn replace_preserve_case(input: &str, pattern: &str, replacement: &str) -> String {
+/// Find pattern as whole words, so "versus" does not match inside "versusapp"
+fn find_word(haystack: &str, pattern: &str) -> Option<usize> {
+    haystack.match_indices(pattern).map(|(i, _)| i).find(|&i| {
+        let before = haystack[..i].chars().next_back();
+        let after = haystack[i + pattern.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// Replace pattern preserving the first letter's case from the original.
+/// Returns None if the pattern does not occur as whole words.
+fn replace_preserve_case(input: &str, pattern: &str, replacement: &str) -> Option<String> {
     let input_lower = input.to_lowercase();
-    if let Some(start) = input_lower.find(pattern) {
+    if let Some(start) = find_word(&input_lower, pattern) {
         // Check if original starts with uppercase
         let orig_char = input.chars().nth(start);
         let replacement_adjusted = if orig_char.map(|c| c.is_uppercase()).unwrap_or(false) {
@@ -90,9 +132,9 @@ fn replace_preserve_case(input: &str, pattern: &str, replacement: &str) -> Strin
         // Replace in original string (case-insensitive position)
         let before = &input[..start];
         let after = &input[start + pattern.len()..];
-        format!("{}{}{}", before, replacement_adjusted, after)
+        Some(format!("{}{}{}", before, replacement_adjusted, after))
     } else {
-        input.to_string()
+        None
     }
 }
 
@@ -108,6 +150,15 @@ mod tests {
         assert_eq!(parse("saint george"), Some("st. george".to_string()));
     }
 
+    #[test]
+    fn test_dot_terms() {
+        assert_eq!(parse("dot net"), Some(".NET".to_string()));
+        assert_eq!(parse("dot com"), Some(".com".to_string()));
+        assert_eq!(parse("dot org"), Some(".org".to_string()));
+        // Only as a standalone term, never inside a domain
+        assert_eq!(parse("example dot com"), None);
+    }
+
     #[test]
     fn test_phrases() {
         assert_eq!(
@@ -116,6 +167,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_versus_et_cetera() {
+        assert_eq!(parse("versus"), Some("vs.".to_string()));
+        assert_eq!(parse("et cetera"), Some("etc.".to_string()));
+        // Standalone only, so sentence mode still converts the numbers around it
+        assert_eq!(parse("twenty one versus forty two"), None);
+        assert_eq!(parse("versusapp"), None);
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        assert_eq!(parse("doctoral thesis"), None);
+        assert_eq!(parse("saints fans"), None);
+    }
+
+    #[test]
+    fn test_that_is_option() {
+        let options = NormalizeOptions {
+            that_is_abbreviation: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("that is", &options),
+            Some("i.e.".to_string())
+        );
+        assert_eq!(parse("that is"), None);
+    }
+
     #[test]
     fn test_tech_terms() {
         assert_eq!(parse("r t x"), Some("RTX".to_string()));
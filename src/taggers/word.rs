@@ -2,17 +2,36 @@
 //!
 //! Handles special word patterns:
 //! - Spelled letters with numbers: "e s three" → "es3"
+//! - List markers: "one a" → "1a", "two b" → "2b"
 //! - Numbers with trailing punctuation: "twenty!" → "20 !"
+//! - Spelled letters: "a b c" → "abc" (with the spell single letters option)
 
 use super::cardinal;
+use crate::NormalizeOptions;
 
 /// Parse special word patterns.
 pub fn parse(input: &str) -> Option<String> {
+    parse_with_options(input, &NormalizeOptions::default())
+}
+
+/// Parse special word patterns, applying output options.
+pub fn parse_with_options(input: &str, options: &NormalizeOptions) -> Option<String> {
+    if options.spell_single_letters {
+        if let Some(result) = parse_spelled_letters(input) {
+            return Some(result);
+        }
+    }
+
     // Try spelled letters with number pattern
     if let Some(result) = parse_spelled_with_number(input) {
         return Some(result);
     }
 
+    // Try list marker
+    if let Some(result) = parse_list_marker(input) {
+        return Some(result);
+    }
+
     // Try number with trailing punctuation
     if let Some(result) = parse_number_with_punctuation(input) {
         return Some(result);
@@ -39,6 +58,13 @@ fn parse_spelled_with_number(input: &str) -> Option<String> {
         return None;
     }
 
+    // A lone "a" or "i" is an article or pronoun ("a five"), not spelling
+    if let [word] = letter_words {
+        if matches!(word.to_lowercase().as_str(), "a" | "i") {
+            return None;
+        }
+    }
+
     let mut result = String::new();
     for word in letter_words {
         // Must be exactly one ASCII letter
@@ -57,6 +83,44 @@ fn parse_spelled_with_number(input: &str) -> Option<String> {
     Some(result)
 }
 
+/// Parse an outline marker, a number followed by a letter: "one a" → "1a"
+/// Only the whole span is matched, so prose like "one a penny" is left alone.
+fn parse_list_marker(input: &str) -> Option<String> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let (letter, number_words) = words.split_last()?;
+    if number_words.is_empty() || number_words.len() > 2 {
+        return None;
+    }
+
+    if letter.len() != 1 || !letter.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    // "o" is a spoken zero ("five o" → 50) and "i" a pronoun or numeral
+    if letter.eq_ignore_ascii_case("o") || letter.eq_ignore_ascii_case("i") {
+        return None;
+    }
+
+    let num = cardinal::words_to_number(&number_words.join(" "))? as i64;
+    if !(1..=99).contains(&num) {
+        return None;
+    }
+    Some(format!("{}{}", num, letter))
+}
+
+/// Parse a run of two or more spelled letters: "a b c" → "abc"
+pub fn parse_spelled_letters(input: &str) -> Option<String> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    if words.len() < 2 {
+        return None;
+    }
+
+    let is_letter = |w: &&str| w.len() == 1 && w.chars().all(|c| c.is_ascii_alphabetic());
+    if !words.iter().all(is_letter) {
+        return None;
+    }
+    Some(words.concat())
+}
+
 /// Parse number word with trailing punctuation: "twenty!" → "20 !"
 fn parse_number_with_punctuation(input: &str) -> Option<String> {
     // Check for trailing punctuation
@@ -87,6 +151,41 @@ mod tests {
     #[test]
     fn test_spelled_with_number() {
         assert_eq!(parse("e s three"), Some("es3".to_string()));
+        assert_eq!(parse("a five"), None);
+    }
+
+    #[test]
+    fn test_list_marker() {
+        assert_eq!(parse("one a"), Some("1a".to_string()));
+        assert_eq!(parse("two b"), Some("2b".to_string()));
+        assert_eq!(parse("twenty one c"), Some("21c".to_string()));
+        assert_eq!(parse("one a penny"), None);
+        assert_eq!(parse("one hundred a"), None);
+        assert_eq!(parse("one"), None);
+        // A spoken zero or a pronoun is not a list letter
+        assert_eq!(parse("five o"), None);
+        assert_eq!(parse("two oh"), None);
+        assert_eq!(parse("three i"), None);
+    }
+
+    #[test]
+    fn test_spelled_letters() {
+        assert_eq!(parse("a b c"), None);
+
+        let options = NormalizeOptions {
+            spell_single_letters: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            parse_with_options("a b c", &options),
+            Some("abc".to_string())
+        );
+        assert_eq!(
+            parse_with_options("J F K", &options),
+            Some("JFK".to_string())
+        );
+        assert_eq!(parse_with_options("a", &options), None);
+        assert_eq!(parse_with_options("a big house", &options), None);
     }
 
     #[test]
@@ -7,6 +7,7 @@ mod common;
 
 use nemo_text_processing::{
     custom_rules, normalize, normalize_sentence, normalize_sentence_with_max_span,
+    normalize_sentence_with_options, NormalizeOptions,
 };
 use std::path::Path;
 
@@ -313,6 +314,14 @@ fn test_sentence_money_in_context() {
         normalize_sentence("I paid five dollars for lunch"),
         "I paid $5 for lunch"
     );
+    assert_eq!(
+        normalize_sentence("the company is worth two point five billion dollars today"),
+        "the company is worth $2.5 billion today"
+    );
+    assert_eq!(
+        normalize_sentence("Acme is worth two point five billion dollars"),
+        "Acme is worth $2.5 billion"
+    );
 }
 
 #[test]
@@ -475,11 +484,10 @@ fn test_sentence_mixed_punctuation_and_numbers() {
         normalize_sentence("I bought twenty three items comma and paid five dollars"),
         "I bought 23 items , and paid $5"
     );
-    // "forty two to thirty seven" is caught by the time tagger (X to Y = time pattern)
-    // This is expected — the time tagger has higher priority than cardinal in parse_span.
+    // "forty two to thirty seven" is not a time: neither part fits a clock
     assert_eq!(
         normalize_sentence("the score was forty two to thirty seven period"),
-        "the score was 36:18 ."
+        "the score was 42 to 37 ."
     );
     assert_eq!(
         normalize_sentence("question mark did you say one hundred"),
@@ -581,7 +589,7 @@ fn test_sentence_adjacent_spans() {
     // With a non-number word separator, they parse as two spans
     assert_eq!(
         normalize_sentence("twenty one versus forty two"),
-        "21 versus 42"
+        "21 vs. 42"
     );
     // "twenty one five dollars" — money tagger matches the longest span including
     // the number prefix, so this becomes "$26" (twenty-one + five = 26 dollars)
@@ -605,6 +613,28 @@ fn test_sentence_single_token() {
 
 // --- Edge: whitespace handling ---
 
+#[test]
+fn test_sentence_attached_punctuation() {
+    assert_eq!(
+        normalize_sentence("I have twenty one, and five dollars."),
+        "I have 21, and $5."
+    );
+    assert_eq!(normalize_sentence("is it one?!"), "is it 1?!");
+    assert_eq!(
+        normalize_sentence("she said \"two apples\""),
+        "she said \"2 apples\""
+    );
+    assert_eq!(normalize_sentence("(twenty one)"), "(21)");
+    assert_eq!(normalize_sentence("one, two, three"), "1, 2, 3");
+    // Pure punctuation tokens and punctuation-free input are unchanged
+    assert_eq!(normalize_sentence("twenty one , and"), "21 , and");
+    assert_eq!(normalize_sentence("two thirty p.m."), "02:30 p.m.");
+    assert_eq!(
+        normalize_sentence_with_max_span("pay twenty one, now", 4),
+        "pay 21, now"
+    );
+}
+
 #[test]
 fn test_sentence_whitespace() {
     assert_eq!(normalize_sentence("  twenty  one  "), "21");
@@ -624,6 +654,29 @@ fn test_sentence_date_in_context() {
     );
 }
 
+#[test]
+fn test_sentence_day_of_month_in_context() {
+    // "the Xth of month" is consumed as one span, so "of" is never left dangling
+    assert_eq!(
+        normalize_sentence("on the fifth of june we met"),
+        "on 5 june we met"
+    );
+    assert_eq!(
+        normalize_sentence("we met on the twenty first of may twenty twenty"),
+        "we met on 21 may 2020"
+    );
+    // Trailing words after the year are not swallowed
+    assert_eq!(
+        normalize_sentence("the fifth of june nineteen ninety and more"),
+        "5 june 1990 and more"
+    );
+    // Without a month, only the ordinal is normalized
+    assert_eq!(
+        normalize_sentence("on the third of them"),
+        "on the 3rd of them"
+    );
+}
+
 // --- Ordinal in various positions ---
 
 #[test]
@@ -636,6 +689,12 @@ fn test_sentence_ordinal_positions() {
     assert_eq!(normalize_sentence("she came in third"), "she came in 3rd");
 }
 
+#[test]
+fn test_sentence_variable_ordinal() {
+    assert_eq!(normalize_sentence("the n th term"), "the nth term");
+    assert_eq!(normalize_sentence("the nth term"), "the nth term");
+}
+
 // --- Measure in sentence ---
 
 #[test]
@@ -646,6 +705,51 @@ fn test_sentence_measure_in_context() {
     );
 }
 
+#[test]
+fn test_sentence_temperature_span_boundary() {
+    // The span ends at the unit; trailing words are left untouched
+    assert_eq!(
+        normalize_sentence("it was thirty degrees celsius outside"),
+        "it was 30 °C outside"
+    );
+    assert_eq!(
+        normalize_sentence("the temperature hit thirty degrees fahrenheit outside"),
+        "the temperature hit 30 °F outside"
+    );
+}
+
+#[test]
+fn test_sentence_negative_temperature() {
+    assert_eq!(
+        normalize_sentence("the temperature was minus five degrees celsius"),
+        "the temperature was -5 °C"
+    );
+    assert_eq!(
+        normalize_sentence("it fell to minus twelve degrees fahrenheit overnight"),
+        "it fell to -12 °F overnight"
+    );
+}
+
+#[test]
+fn test_sentence_preserves_surrounding_case() {
+    assert_eq!(
+        normalize_sentence("Flight to Paris weighs five kilograms Monday"),
+        "Flight to Paris weighs 5 kg Monday"
+    );
+    assert_eq!(
+        normalize_sentence("The Tower is three hundred meters Tall"),
+        "The Tower is 300 m Tall"
+    );
+    assert_eq!(
+        normalize_sentence("Sales grew five point two Million in March"),
+        "Sales grew 5.2 Million in March"
+    );
+    assert_eq!(
+        normalize_sentence("At two thirty PM Alice left"),
+        "At 02:30 P.M. Alice left"
+    );
+}
+
 // --- Doctor / title in sentence (whitelist) ---
 
 #[test]
@@ -656,6 +760,31 @@ fn test_sentence_whitelist_in_context() {
     );
 }
 
+#[test]
+fn test_sentence_versus_et_cetera() {
+    assert_eq!(
+        normalize_sentence("the lakers versus the celtics tonight"),
+        "the lakers vs. the celtics tonight"
+    );
+    assert_eq!(
+        normalize_sentence("bring plates cups et cetera"),
+        "bring plates cups etc."
+    );
+    assert_eq!(normalize_sentence("that is correct"), "that is correct");
+}
+
+#[test]
+fn test_sentence_dot_terms() {
+    assert_eq!(
+        normalize_sentence("built with dot net framework"),
+        "built with .NET framework"
+    );
+    assert_eq!(
+        normalize_sentence("visit nvidia dot com today"),
+        "visit nvidia.com today"
+    );
+}
+
 // =============================================================================
 // ASR-realistic edge cases (typical dictation output)
 // =============================================================================
@@ -694,6 +823,276 @@ fn test_sentence_large_numbers() {
     );
 }
 
+#[test]
+fn test_sentence_sizes() {
+    assert_eq!(
+        normalize_sentence("i wear size ten and a half shoes"),
+        "i wear size 10.5 shoes"
+    );
+    assert_eq!(
+        normalize_sentence("do you have size eleven"),
+        "do you have size 11"
+    );
+}
+
+#[test]
+fn test_sentence_steps() {
+    assert_eq!(
+        normalize_sentence("for step one open the lid"),
+        "for step 1 open the lid"
+    );
+    assert_eq!(
+        normalize_sentence("you reached level three"),
+        "you reached level 3"
+    );
+    let options = NormalizeOptions {
+        title_case_steps: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        normalize_sentence_with_options("for step one open the lid", &options),
+        "for Step 1 open the lid"
+    );
+    // A duration after the cue is not a step number
+    assert_eq!(
+        normalize_sentence("the next step two days later"),
+        "the next step 2 days later"
+    );
+}
+
+#[test]
+fn test_sentence_versions() {
+    assert_eq!(
+        normalize_sentence("update to firmware one point oh point two now"),
+        "update to firmware 1.0.2 now"
+    );
+    assert_eq!(
+        normalize_sentence("we shipped version two point one today"),
+        "we shipped version 2.1 today"
+    );
+    assert_eq!(
+        normalize_sentence("the software two point five million"),
+        "the software 2.5 million"
+    );
+}
+
+#[test]
+fn test_sentence_decimal_followed_by_word() {
+    // The decimal span stops at the first non-numeric word
+    assert_eq!(
+        normalize_sentence("three point fourteen version"),
+        "3.14 version"
+    );
+    assert_eq!(
+        normalize_sentence("we use three point one four version now"),
+        "we use 3.14 version now"
+    );
+}
+
+#[test]
+fn test_sentence_malformed_decimal_passes_through() {
+    assert_eq!(
+        normalize_sentence("he said three point five hundred times"),
+        "he said three point five hundred times"
+    );
+    assert_eq!(
+        normalize_sentence("it weighs three point five kilograms"),
+        "it weighs 3.5 kg"
+    );
+}
+
+#[test]
+fn test_sentence_dotted_initials() {
+    assert_eq!(
+        normalize_sentence("made in the u dot s dot a today"),
+        "made in the U.S.A. today"
+    );
+    assert_eq!(
+        normalize_sentence("send it to p dot o dot box five"),
+        "send it to P.O. box 5"
+    );
+}
+
+#[test]
+fn test_sentence_hundreds_ordinals() {
+    assert_eq!(
+        normalize_sentence("the one hundred and first day"),
+        "the 101st day"
+    );
+    assert_eq!(
+        normalize_sentence("on the two hundred and twenty second day of the year"),
+        "on the 222nd day of the year"
+    );
+    assert_eq!(
+        normalize_sentence("the one hundred and fiftieth anniversary"),
+        "the 150th anniversary"
+    );
+    assert_eq!(
+        normalize_sentence("the two thousand and twenty third edition"),
+        "the 2023rd edition"
+    );
+}
+
+#[test]
+fn test_sentence_ages() {
+    assert_eq!(
+        normalize_sentence("she is twenty one years old"),
+        "she is 21 years old"
+    );
+    assert_eq!(
+        normalize_sentence("a twenty one year old woman"),
+        "a 21 year old woman"
+    );
+}
+
+#[test]
+fn test_sentence_headings() {
+    assert_eq!(
+        normalize_sentence("turn left heading two seven zero degrees now"),
+        "turn left heading 270° now"
+    );
+    // "of course" is not a navigation cue
+    assert!(!normalize_sentence("of course one two three degrees").contains('°'));
+}
+
+#[test]
+fn test_sentence_decimal_word() {
+    assert_eq!(
+        normalize_sentence("pi is roughly three decimal one four"),
+        "pi is roughly 3.14"
+    );
+    assert_eq!(
+        normalize_sentence("store it as a decimal number"),
+        "store it as a decimal number"
+    );
+}
+
+#[test]
+fn test_sentence_word_fractions() {
+    assert_eq!(
+        normalize_sentence("add two and a half cups of flour"),
+        "add 2 1/2 cups of flour"
+    );
+    assert_eq!(
+        normalize_sentence("three quarters of the vote"),
+        "3/4 of the vote"
+    );
+    assert_eq!(
+        normalize_sentence("it took an hour and a half"),
+        "it took an hour and a half"
+    );
+    assert_eq!(
+        normalize_sentence("the second half and a quarter"),
+        "the 2nd half and a quarter"
+    );
+}
+
+#[test]
+fn test_sentence_flight_numbers() {
+    assert_eq!(
+        normalize_sentence("board flight two oh five now"),
+        "board flight 205 now"
+    );
+    assert_eq!(normalize_sentence("the flight two hours"), "the flight 2 h");
+}
+
+#[test]
+fn test_sentence_seat_codes() {
+    assert_eq!(
+        normalize_sentence("board at gate b twenty for seat fourteen c"),
+        "board at gate B20 for seat 14C"
+    );
+    assert_eq!(
+        normalize_sentence("walk past the gate a hundred meters away"),
+        "walk past the gate 100 m away"
+    );
+    // "seat" as a verb
+    assert_eq!(
+        normalize_sentence("the host will seat a ten person group"),
+        "the host will seat a 10 person group"
+    );
+    assert_eq!(
+        normalize_sentence("the gate a ten minute walk away"),
+        "the gate a 10 min walk away"
+    );
+}
+
+#[test]
+fn test_sentence_coordinates() {
+    assert_eq!(
+        normalize_sentence(
+            "the pin is at latitude minus thirty three point eight six eight and longitude one hundred fifty one point two zero nine three"
+        ),
+        "the pin is at latitude -33.868 and longitude 151.2093"
+    );
+}
+
+#[test]
+fn test_sentence_race_times() {
+    assert_eq!(
+        normalize_sentence("she ran one forty five point three today"),
+        "she ran 1:45.3 today"
+    );
+    // No cue, no lap time
+    assert!(!normalize_sentence("the pH was seven fifty point two").contains(':'));
+    assert!(!normalize_sentence("the stock closed at one forty five point three").contains(':'));
+}
+
+#[test]
+fn test_sentence_blood_pressure() {
+    assert_eq!(
+        normalize_sentence("her blood pressure is one twenty over eighty today"),
+        "her blood pressure is 120/80 today"
+    );
+    assert_eq!(
+        normalize_sentence("Blood pressure is one twenty over eighty"),
+        "Blood pressure is 120/80"
+    );
+}
+
+#[test]
+fn test_sentence_ranges() {
+    assert_eq!(
+        normalize_sentence("it costs between ten and twenty"),
+        "it costs 10-20"
+    );
+    assert_eq!(
+        normalize_sentence("a walk of five to ten kilometers each day"),
+        "a walk of 5-10 km each day"
+    );
+    // "to" belongs to the range, not a "ten to twenty" time reading
+    assert_eq!(
+        normalize_sentence("prices rose ten to twenty percent last year"),
+        "prices rose 10-20 % last year"
+    );
+    assert_eq!(
+        normalize_sentence("we are open from nine to five o'clock on weekdays"),
+        "we are open 09:00-17:00 on weekdays"
+    );
+    // Other ranges leave "from" in the text
+    assert_eq!(
+        normalize_sentence("prices from five to ten dollars"),
+        "prices from $5-$10"
+    );
+    assert_eq!(
+        normalize_sentence("count from twenty to fifty"),
+        "count from 20-50"
+    );
+}
+
+#[test]
+fn test_sentence_dozen_and_score() {
+    assert_eq!(normalize_sentence("buy two dozen eggs"), "buy 24 eggs");
+    assert_eq!(
+        normalize_sentence("dozens of people came"),
+        "dozens of people came"
+    );
+    assert_eq!(
+        normalize_sentence("what was the score today"),
+        "what was the score today"
+    );
+}
+
 // --- Negative numbers in sentence ---
 
 #[test]
@@ -741,6 +1140,18 @@ fn test_sentence_punctuation_dictation() {
     );
 }
 
+#[test]
+fn test_sentence_bracket_citations() {
+    assert_eq!(
+        normalize_sentence("as shown in open bracket five close bracket"),
+        "as shown in [5]"
+    );
+    assert_eq!(
+        normalize_sentence("see open bracket twelve close bracket and more"),
+        "see [12] and more"
+    );
+}
+
 // --- Ordinal false positives (known limitation) ---
 // "first", "second", "third" are caught by ordinal tagger even when used as adjectives.
 // This is a known limitation — Rust has no POS tagger. Swift NLTagger handles this.
@@ -840,3 +1251,23 @@ fn test_sentence_decimal_in_context() {
         "the value is 3.14"
     );
 }
+
+#[test]
+fn test_sentence_pi_not_ip_or_phone() {
+    assert_eq!(
+        normalize_sentence("pi is three point one four"),
+        "pi is 3.14"
+    );
+}
+
+#[test]
+fn test_sentence_out_of() {
+    assert_eq!(
+        normalize_sentence("one out of four people agreed"),
+        "1/4 people agreed"
+    );
+    assert_eq!(
+        normalize_sentence("get one out of the box"),
+        "get 1 out of the box"
+    );
+}